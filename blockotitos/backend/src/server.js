@@ -10,6 +10,7 @@ import {
   approveCreator,
   revokeCreatorApproval,
   createEvent,
+  publishEvent,
   getAdminAddress,
   claimPoap,
   getEventCount,
@@ -208,16 +209,23 @@ app.get("/health", (_req, res) => {
   res.json({ status: "ok" });
 });
 
+// Creator plans accepted by the SPOT contract's `approve_creator`
+const PLANS = ["Free", "Starter", "Pro", "Enterprise"];
+
 // --- ENDPOINTS ---
 
 app.post("/creators/approve", async (req, res) => {
-  const { creator, paymentReference } = req.body || {};
-  const payload = { creator, paymentReference };
+  const { creator, paymentReference, plan = "Free", credits = 0 } = req.body || {};
+  const payload = { creator, paymentReference, plan, credits };
   
   if (!creator || !paymentReference) {
     return res.status(400).json({ error: "creator and paymentReference are required" });
   }
 
+  if (!PLANS.includes(plan) || !Number.isInteger(credits) || credits < 0) {
+    return res.status(400).json({ error: `plan must be one of ${PLANS.join(", ")} and credits a non-negative integer` });
+  }
+
   if (isMock) {
     const txHash = `MOCK-APPROVE-${Date.now()}`;
     const signedEnvelope = Buffer.from(`MOCK-APPROVE-ENVELOPE-${Date.now()}`).toString("base64");
@@ -229,6 +237,8 @@ app.post("/creators/approve", async (req, res) => {
     const result = await approveCreator({
       creator,
       paymentReference,
+      plan,
+      credits,
       rpcUrl: RPC_URL,
       networkPassphrase: NETWORK_PASSPHRASE,
       adminSecret: ADMIN_SECRET,
@@ -243,8 +253,8 @@ app.post("/creators/approve", async (req, res) => {
 });
 
 app.post("/creators/revoke", async (req, res) => {
-  const { creator } = req.body || {};
-  const payload = { creator };
+  const { creator, refund = false } = req.body || {};
+  const payload = { creator, refund: Boolean(refund) };
   if (!creator) {
     return res.status(400).json({ error: "creator is required" });
   }
@@ -259,6 +269,7 @@ app.post("/creators/revoke", async (req, res) => {
   try {
     const result = await revokeCreatorApproval({
       creator,
+      refund: Boolean(refund),
       rpcUrl: RPC_URL,
       networkPassphrase: NETWORK_PASSPHRASE,
       adminSecret: ADMIN_SECRET,
@@ -364,20 +375,36 @@ app.post("/events/create", upload.single("image"), async (req, res) => {
       imageUrl: finalImageUrl,
     });
     
-    let eventId;
-    try {
-      eventId = await getEventCount({
-        rpcUrl: RPC_URL,
-        networkPassphrase: NETWORK_PASSPHRASE,
-        contractId: CONTRACT_ID,
-        adminSecret: ADMIN_SECRET,
-      });
-    } catch (countError) {
-      console.warn("Unable to fetch event count after creation:", countError);
-    }
-    
+    // `create_event` returns the new event ID
+    const eventId = result.returnValue === undefined ? undefined : Number(result.returnValue);
     await logTx({ action: "create_event", status: "success", txHash: result.txHash, payload: { ...payload, eventId }, rpcResponse: result.rpcResponse, signedEnvelope: result.envelopeXdr });
-    res.json({ txHash: result.txHash, rpcResponse: result.rpcResponse, signedEnvelope: result.envelopeXdr, eventId, imageUrl: finalImageUrl });
+
+    // Events are created as drafts; publish right away so they are claimable.
+    // The event exists even if publishing fails, so report it and let the caller retry
+    // with POST /events/:eventId/publish.
+    let published = false;
+    let publishError;
+    if (eventId !== undefined) {
+      try {
+        const publishResult = await publishEvent({
+          rpcUrl: RPC_URL,
+          networkPassphrase: NETWORK_PASSPHRASE,
+          contractId: CONTRACT_ID,
+          signerSecret: ADMIN_SECRET,
+          operator: ADMIN_PUBLIC_KEY,
+          eventId,
+        });
+        published = true;
+        await logTx({ action: "publish_event", status: "success", txHash: publishResult.txHash, payload: { eventId }, rpcResponse: publishResult.rpcResponse, signedEnvelope: publishResult.envelopeXdr });
+      } catch (error) {
+        publishError = error.message || String(error);
+        await logTx({ action: "publish_event", status: "error", error: publishError, payload: { eventId } });
+      }
+    } else {
+      publishError = "Event ID missing from create_event result";
+    }
+
+    res.json({ txHash: result.txHash, rpcResponse: result.rpcResponse, signedEnvelope: result.envelopeXdr, eventId, published, publishError, imageUrl: finalImageUrl });
   } catch (error) {
     await cleanupUploadedFile(req.file);
     await logTx({ action: "create_event", status: "error", error: error.message || String(error), payload });
@@ -385,6 +412,41 @@ app.post("/events/create", upload.single("image"), async (req, res) => {
   }
 });
 
+app.post("/events/:eventId/publish", async (req, res) => {
+  const eventId = Number(req.params.eventId);
+  const payload = { eventId };
+  if (!Number.isInteger(eventId) || eventId <= 0) {
+    return res.status(400).json({ error: "Invalid eventId" });
+  }
+
+  if (isMock) {
+    const txHash = `MOCK-PUBLISH-${Date.now()}`;
+    const signedEnvelope = Buffer.from(`MOCK-PUBLISH-ENVELOPE-${Date.now()}`).toString("base64");
+    await logTx({ action: "publish_event", status: "success", txHash, payload, signedEnvelope });
+    return res.json({ txHash, signedEnvelope, rpcResponse: { status: "MOCK" } });
+  }
+
+  if (!ADMIN_SECRET || !ADMIN_PUBLIC_KEY) {
+    return res.status(500).json({ error: "Admin credentials not configured" });
+  }
+
+  try {
+    const result = await publishEvent({
+      rpcUrl: RPC_URL,
+      networkPassphrase: NETWORK_PASSPHRASE,
+      contractId: CONTRACT_ID,
+      signerSecret: ADMIN_SECRET,
+      operator: ADMIN_PUBLIC_KEY,
+      eventId,
+    });
+    await logTx({ action: "publish_event", status: "success", txHash: result.txHash, payload, rpcResponse: result.rpcResponse, signedEnvelope: result.envelopeXdr });
+    res.json({ txHash: result.txHash, rpcResponse: result.rpcResponse, signedEnvelope: result.envelopeXdr });
+  } catch (error) {
+    await logTx({ action: "publish_event", status: "error", error: error.message || String(error), payload });
+    res.status(500).json({ error: error.message || String(error) });
+  }
+});
+
 app.get("/events/:eventId/minted-count", async (req, res) => {
   const eventId = Number(req.params.eventId);
   if (Number.isNaN(eventId)) {
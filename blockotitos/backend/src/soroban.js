@@ -12,6 +12,8 @@ import {
 const BASE_FEE = "100";
 const POLL_INTERVAL_MS = 1000;
 const POLL_RETRIES = 60;
+// Page size of `get_all_events` (the contract caps pages at 50 event IDs)
+const EVENT_PAGE_SIZE = 50;
 
 function isUnionSwitchError(error) {
   return (
@@ -69,9 +71,27 @@ function decodeEventData(scVal) {
     claimEnd: toU64Number(data.claim_end),
     metadataUri: toStringVal(data.metadata_uri),
     imageUrl: toStringVal(data.image_url),
+    status: data.status ? data.status.vec()[0].sym().toString() : "",
   };
 }
 
+function extractReturnValue(tx) {
+  if (tx.returnValue) {
+    return scValToNative(tx.returnValue);
+  }
+  // Raw getTransaction responses only carry the XDR meta
+  if (typeof tx.resultMetaXdr !== "string") {
+    return undefined;
+  }
+  try {
+    const meta = xdr.TransactionMeta.fromXDR(tx.resultMetaXdr, "base64");
+    const returnValue = meta.v3().sorobanMeta()?.returnValue();
+    return returnValue ? scValToNative(returnValue) : undefined;
+  } catch (_) {
+    return undefined;
+  }
+}
+
 function getServer(rpcUrl) {
   return new SorobanRpc.Server(rpcUrl, { allowHttp: rpcUrl.startsWith("http://") });
 }
@@ -102,7 +122,7 @@ async function pollForCompletion(server, hash) {
     }
 
     if (tx.status === SorobanRpc.Api.GetTransactionStatus.SUCCESS) {
-      return hash;
+      return tx;
     }
     if (tx.status === SorobanRpc.Api.GetTransactionStatus.FAILED) {
       throw new Error(`Transaction failed: ${formatResultXdr(tx.resultXdr)}`);
@@ -149,8 +169,13 @@ async function sendContractInvocation({
     throw new Error(`Soroban rejected tx: ${sendResult.resultXdr}`);
   }
 
-  const txHash = await pollForCompletion(server, sendResult.hash);
-  return { txHash, rpcResponse: sendResult, envelopeXdr };
+  const completedTx = await pollForCompletion(server, sendResult.hash);
+  return {
+    txHash: sendResult.hash,
+    rpcResponse: sendResult,
+    envelopeXdr,
+    returnValue: extractReturnValue(completedTx),
+  };
 }
 
 async function simulateContractCall({
@@ -189,6 +214,8 @@ export async function approveCreator({
   contractId,
   creator,
   paymentReference,
+  plan = "Free",
+  credits = 0,
   adminSecret,
 }) {
   const adminPublic = Keypair.fromSecret(adminSecret).publicKey();
@@ -196,6 +223,8 @@ export async function approveCreator({
     Address.fromString(adminPublic).toScVal(),
     Address.fromString(creator).toScVal(),
     nativeToScVal(paymentReference, { type: "string" }),
+    xdr.ScVal.scvVec([xdr.ScVal.scvSymbol(plan)]),
+    nativeToScVal(credits, { type: "u32" }),
   ];
 
   return sendContractInvocation({
@@ -213,12 +242,14 @@ export async function revokeCreatorApproval({
   networkPassphrase,
   contractId,
   creator,
+  refund = false,
   adminSecret,
 }) {
   const adminPublic = Keypair.fromSecret(adminSecret).publicKey();
   const args = [
     Address.fromString(adminPublic).toScVal(),
     Address.fromString(creator).toScVal(),
+    nativeToScVal(refund, { type: "bool" }),
   ];
 
   return sendContractInvocation({
@@ -273,6 +304,29 @@ export async function createEvent({
   });
 }
 
+export async function publishEvent({
+  rpcUrl,
+  networkPassphrase,
+  contractId,
+  signerSecret,
+  operator,
+  eventId,
+}) {
+  const args = [
+    Address.fromString(operator).toScVal(),
+    nativeToScVal(eventId, { type: "u32" }),
+  ];
+
+  return sendContractInvocation({
+    rpcUrl,
+    networkPassphrase,
+    signerSecret,
+    contractId,
+    method: "publish_event",
+    args,
+  });
+}
+
 export async function claimPoap({
   rpcUrl,
   networkPassphrase,
@@ -281,7 +335,11 @@ export async function claimPoap({
   claimer,
   eventId,
 }) {
+  // `claim` requires the claimer's signature; the backend mints on their behalf through
+  // `claim_sponsored`, so the payer must be an admin or a minter of the event
+  const operatorPublic = Keypair.fromSecret(payerSecret).publicKey();
   const args = [
+    Address.fromString(operatorPublic).toScVal(),
     nativeToScVal(eventId, { type: "u32" }),
     Address.fromString(claimer).toScVal(),
   ];
@@ -291,7 +349,7 @@ export async function claimPoap({
     networkPassphrase,
     signerSecret: payerSecret,
     contractId,
-    method: "claim",
+    method: "claim_sponsored",
     args,
   });
 }
@@ -381,18 +439,33 @@ export async function getAllEventIds({
   contractId,
   adminSecret,
 }) {
-  const raw = await simulateContractCall({
+  const eventCount = await getEventCount({
     rpcUrl,
     networkPassphrase,
-    signerSecret: adminSecret,
     contractId,
-    method: "get_all_events",
-    args: [],
-    decode: false,
+    adminSecret,
   });
 
-  const vec = raw?.vec() || [];
-  return vec.map((entry) => Number(entry.u32()));
+  const eventIds = [];
+  for (let start = 0; start < eventCount; start += EVENT_PAGE_SIZE) {
+    const raw = await simulateContractCall({
+      rpcUrl,
+      networkPassphrase,
+      signerSecret: adminSecret,
+      contractId,
+      method: "get_all_events",
+      args: [
+        xdr.ScVal.scvVoid(),
+        nativeToScVal(start, { type: "u32" }),
+        nativeToScVal(EVENT_PAGE_SIZE, { type: "u32" }),
+      ],
+      decode: false,
+    });
+
+    const vec = raw?.vec() || [];
+    eventIds.push(...vec.map((entry) => Number(entry.u32())));
+  }
+  return eventIds;
 }
 
 export async function getEventDetails({
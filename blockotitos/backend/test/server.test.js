@@ -161,6 +161,23 @@ test("POST /events/claim validates missing fields", async () => {
   assert.equal(entries.length, 0);
 });
 
+test("POST /events/:eventId/publish succeeds (mock mode)", async () => {
+  const response = await request(app).post("/events/1/publish");
+
+  assert.equal(response.statusCode, 200);
+  assert.match(response.body.txHash, /^MOCK-PUBLISH-/);
+
+  const entries = await readLogEntries();
+  assert.equal(entries.length, 1);
+  assert.equal(entries[0].action, "publish_event");
+  assert.equal(entries[0].payload.eventId, 1);
+});
+
+test("POST /events/:eventId/publish validates eventId", async () => {
+  const response = await request(app).post("/events/abc/publish");
+  assert.equal(response.statusCode, 400);
+});
+
 test("GET /contract/event-count returns mock value", async () => {
   const response = await request(app).get("/contract/event-count");
 
@@ -4,7 +4,11 @@
 //! It handles event creation, minting, burning, role-based access control, and claim period validation.
 //! All events are managed in a single contract instance.
 
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, String, Vec};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error, symbol_short, token, xdr::ToXdr,
+    Address, Bytes, BytesN, Env, String, Symbol, Vec,
+};
+use spot_timelock::{self as timelock, Operation, TimelockAction, ACCEPT_WINDOW_LEDGERS};
 use stellar_access::access_control::{self as access_control, AccessControl};
 use stellar_macros::default_impl;
 use stellar_tokens::non_fungible::{
@@ -14,7 +18,22 @@ use stellar_tokens::non_fungible::{
 };
 
 use crate::error::SpotError;
+use crate::storage;
+use crate::events::{
+    AdminGranted, ApprovalPriceUpdated, ApprovalPurchased, ApproverChangeSigned, ApproversUpdated,
+    ClaimCommitted, CollectionAdded, CollectionBadgeMinted, ContractUpgraded, CreatorApprovalSigned,
+    CreatorApproved, CreatorProposalCancelled, CreatorRevoked, DonationConfigUpdated,
+    DonationReceived, EventCreated, EventFieldChanged, EventOwnerChanged, EventOwnerProposed,
+    EventPauseChanged, EventRoleGranted, EventRoleRevoked, EventStatusChanged, EventUpdated,
+    LocationVerified, PauseChanged, SchemaMigrated, SpotClaimed, TreasuryWithdrawn,
+};
+use crate::geo;
+use crate::migration;
+use crate::multisig::{self, ApproverChange, ApproverSet, CreatorProposal};
 
+/// Storage keys. `EventCounter`, `SchemaVersion`, `MigrationCursor`, `LegacyClaims` and
+/// `Paused` live in instance storage, `ClaimCommit` in temporary storage; every other per-event and per-claim key
+/// lives in persistent storage (see `storage.rs`).
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
@@ -25,6 +44,38 @@ pub enum DataKey {
     EventTokenId(u32, u32),        // Map event_id + token_index to token_id
     UserEventTokenId(u32, Address), // Map event_id + address to token_id (for efficient lookup)
     CreatorApproval(Address),      // Tracks off-chain payment approval for creators
+    VoucherIssuer(u32),            // ed25519 public key that signs claim vouchers for an event
+    VoucherNonce(u32, u64),        // Marks a voucher nonce as used for an event
+    ClaimCode(u32),                // Shared claim code (hash + remaining uses) for an event
+    ClaimCommit(u32, BytesN<32>),  // Commit–reveal commitment -> ledger it was made in (temporary)
+    ClaimLink(u32, BytesN<32>),    // One-time claim link (keyed by sha256 of the link ID)
+    LinksRemaining(u32),           // Number of registered links not yet used or revoked
+    NfcTag(u32, BytesN<32>),       // Registered NFC tag ed25519 public key for an event
+    Geofence(u32),                 // Geofence (centre, radius, attestor key) for an event
+    EventRole(u32, Address),       // Event-scoped role of a delegate
+    EventDelegates(u32),           // Addresses holding an event-scoped role (for listing)
+    TokenEvent(u32),               // Map token_id to the event it was minted for
+    CollectionCount(u32),          // Number of collections of an event
+    Collection(u32, u32),          // Collection of an event (event_id, collection_id)
+    CollectionClaimed(u32, u32, Address), // Track if an address has a badge of a collection
+    TokenCollection(u32),          // Map token_id to its collection (absent = default event badge)
+    CreatorCredits(Address),       // Badge credits of a paid-plan creator
+    EventPlan(u32),                // Account charged for the event and its plan (absent = admin event)
+    EventCredits(u32),             // Credits consumed by an event, refundable when it ends
+    ApprovalPrice(Address, Plan),  // Self-service approval price of a plan in a token (SAC)
+    DonationConfig(Address),       // Donor event and badge tiers for donations in a token
+    DonorTotal(Address, Address),  // Cumulative donations of a donor in a token (donor, token)
+    SchemaVersion,                 // Storage schema version (instance, see `migration.rs`)
+    MigrationCursor,               // Progress of an in-progress migration (instance)
+    Approvers,                     // Approver set for M-of-N creator onboarding (see `multisig.rs`)
+    CreatorProposal(Address, String), // Pending creator approval (creator, payment_reference)
+    ApproverProposal(ApproverChange), // Signers of a pending change to the approver set
+    Paused,                        // Contract-wide emergency pause (instance)
+    EventPause(u32),               // Emergency pause of a single event
+    PendingEventOwner(u32),        // Proposed new owner of an event, awaiting acceptance
+    EventChanges(u32),             // History of capacity and claim window edits (Vec<EventChange>)
+    NfcTagCount(u32),              // Number of NFC tags registered for an event
+    LegacyClaims,                  // Set when migrated from schema 1 (instance, see `migration.rs`)
 }
 
 #[contracttype]
@@ -41,6 +92,18 @@ pub struct EventData {
     pub claim_end: u64,
     pub metadata_uri: String,
     pub image_url: String,
+    pub allowlist_root: Option<BytesN<32>>, // Merkle root of allowed claimers (invite-only events)
+    pub status: EventStatus,
+}
+
+/// Event lifecycle: Draft -> Published -> Closed, or Draft/Published -> Cancelled
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventStatus {
+    Draft,     // Editable, not claimable
+    Published, // Claimable; name, date and metadata URI are frozen
+    Cancelled, // Not claimable; existing badges are marked as cancelled
+    Closed,    // Not claimable; supply is final
 }
 
 #[contracttype]
@@ -49,6 +112,232 @@ pub struct CreatorApproval {
     pub payment_reference: String,
     pub approved_at: u64,
     pub approved_by: Address,
+    pub plan: Plan,
+    pub payment: Option<CreatorPayment>, // On-chain payment, None for off-chain approvals
+}
+
+/// Payment made into the contract treasury for a self-service approval
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorPayment {
+    pub token: Address,
+    pub amount: i128,
+    pub credits: u32, // Credits the payment bought
+}
+
+/// Price of a plan for self-service approval, and the credits it includes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalPrice {
+    pub amount: i128,
+    pub credits: u32,
+}
+
+/// Event fields with guarded edits and an on-chain change history
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventField {
+    MaxPoaps,
+    ClaimStart,
+    ClaimEnd,
+}
+
+/// Entry of an event's change history
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventChange {
+    pub field: EventField,
+    pub old_value: u64,
+    pub new_value: u64,
+    pub operator: Address,
+    pub changed_at: u64,
+}
+
+/// Maximum number of entries kept in an event's change history; older entries are dropped
+pub const MAX_EVENT_CHANGES: u32 = 50;
+
+/// Maximum number of event IDs scanned by one `get_all_events` page
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// How long after `claim_end` an ended claim window can still be reopened (one week)
+pub const REOPEN_GRACE_PERIOD: u64 = 7 * 24 * 60 * 60;
+
+/// Creator plan tiers (see docs/ARCHITECTURE.md)
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Plan {
+    Free,       // Up to `FREE_MAX_POAPS` badges and a one-week claim window, no credits
+    Starter,    // Paid tiers consume one credit per badge of capacity
+    Pro,
+    Enterprise,
+}
+
+/// Maximum badges per event on the free plan
+pub const FREE_MAX_POAPS: u32 = 100;
+
+/// Maximum claim window on the free plan (one week)
+pub const FREE_MAX_CLAIM_WINDOW: u64 = 7 * 24 * 60 * 60;
+
+/// Claim voucher (QR method) signed off-chain by the event's issuer key
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimVoucher {
+    pub event_id: u32,
+    pub recipient: Option<Address>, // None = any recipient (wildcard)
+    pub expires_at: u64,
+    pub nonce: u64,
+}
+
+/// Shared claim code ("Código Compartido") registered for an event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimCode {
+    pub code_hash: BytesN<32>, // sha256 of the code
+    pub uses_remaining: u32,
+    pub require_commit: bool,  // Code must be claimed through commit–reveal
+}
+
+/// Number of ledgers (~1 hour) a claim commitment stays valid for its reveal
+pub const REVEAL_WINDOW_LEDGERS: u32 = 720;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LinkStatus {
+    Active,
+    Used,
+    Revoked,
+}
+
+/// One-time claim link ("Unique Link" method)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimLink {
+    pub expires_at: u64,
+    pub status: LinkStatus,
+}
+
+/// Maximum validity of a claim link (one week, free plan limit)
+pub const MAX_LINK_VALIDITY: u64 = 7 * 24 * 60 * 60;
+
+/// Maximum number of links registered or revoked in one call
+pub const MAX_LINK_BATCH: u32 = 100;
+
+/// Maximum number of NFC tags registered in one call
+pub const MAX_NFC_BATCH: u32 = 50;
+
+/// Number of ledgers (~5 minutes) an NFC challenge nonce stays valid
+pub const NFC_NONCE_WINDOW_LEDGERS: u32 = 60;
+
+/// Event geofence. Coordinates are fixed-point microdegrees (degrees * 1_000_000).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Geofence {
+    pub latitude: i32,
+    pub longitude: i32,
+    pub radius_m: u32,
+    pub attestor: BytesN<32>, // ed25519 key of the service that signs location attestations
+}
+
+/// Location proof signed by the event's attestor
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LocationAttestation {
+    pub event_id: u32,
+    pub claimer: Address,
+    pub latitude: i32,
+    pub longitude: i32,
+    pub timestamp: u64,
+}
+
+/// Maximum age (in seconds) of a location attestation
+pub const MAX_ATTESTATION_AGE: u64 = 10 * 60;
+
+/// Event-scoped roles granted by the event creator
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventRole {
+    Minter,  // Can issue sponsored claims for the event
+    Manager, // Can update the event and configure its claim methods; also a minter
+    Viewer,  // Read-only access to the event in the dashboard
+    Organizer, // Co-organizer: the owner's rights except ownership and co-organizer changes
+}
+
+/// A delegate of an event and their role
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventDelegate {
+    pub account: Address,
+    pub role: EventRole,
+}
+
+/// Maximum number of delegates per event
+pub const MAX_EVENT_DELEGATES: u32 = 20;
+
+/// Proposed new owner of an event, who must accept by `live_until_ledger`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingOwner {
+    pub account: Address,
+    pub live_until_ledger: u32,
+}
+
+/// Per-recipient outcome of an airdrop
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AirdropResult {
+    Minted(u32),    // Token ID of the new badge
+    AlreadyClaimed, // Recipient already holds a badge for the event (skipped)
+    LimitExceeded,  // `max_poaps` reached before this recipient (skipped)
+}
+
+/// Maximum number of recipients in one airdrop call
+pub const MAX_AIRDROP_BATCH: u32 = 100;
+
+/// Badge collection (tier) within an event, e.g. speaker or volunteer.
+/// Collections have their own supply and do not count against the event's `max_poaps`,
+/// which only caps the default event badge.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Collection {
+    pub collection_id: u32,
+    pub name: String,
+    pub max_supply: u32,      // Public cap for self-service claims
+    pub reserved_supply: u32, // Minted by the organizers, outside the public cap
+    pub claim_start: u64,
+    pub claim_end: u64,
+    pub metadata_uri: String,
+    pub image_url: String,
+    pub minted: u32,
+    pub reserved_minted: u32,
+}
+
+/// Maximum number of collections per event
+pub const MAX_COLLECTIONS: u32 = 10;
+
+/// Donor badge tier: donors whose cumulative total reaches `min_total` receive a badge
+/// from `collection_id` of the donor event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonorTier {
+    pub min_total: i128,
+    pub collection_id: u32,
+}
+
+/// Donations accepted in a token and the donor event their badges come from
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationConfig {
+    pub event_id: u32,
+    pub tiers: Vec<DonorTier>,
+}
+
+/// Emergency pause and why it was set
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseInfo {
+    pub reason: String,
+    pub paused_by: Address,
+    pub paused_at: u64,
 }
 
 #[contract]
@@ -69,11 +358,13 @@ impl Spot {
             String::from_str(e, "SPOT"),
         );
 
-        // Initialize access control
+        // Initialize access control; holders of the admin role may manage creators
         access_control::set_admin(e, &admin);
+        access_control::set_role_admin_no_auth(e, &symbol_short!("creator"), &symbol_short!("admin"));
 
         // Initialize event counter
         e.storage().instance().set(&DataKey::EventCounter, &0u32);
+        migration::set_schema_version(e, migration::SCHEMA_VERSION);
     }
 
     /// Get the admin address
@@ -81,7 +372,245 @@ impl Spot {
         access_control::get_admin(e).ok_or(SpotError::Unauthorized)
     }
 
-    /// Create a new SPOT event
+    /// Schedule an upgrade of the contract code (only the contract admin).
+    /// The upgrade runs through `execute_operation` once the timelock delay has passed;
+    /// run `migrate` afterwards if the new code bumps the schema version.
+    ///
+    /// # Returns
+    /// The ID of the scheduled operation
+    pub fn upgrade(e: &Env, operator: Address, new_wasm_hash: BytesN<32>) -> Result<u64, SpotError> {
+        operator.require_auth();
+        Self::require_contract_admin(e, &operator)?;
+
+        let operation = timelock::schedule(e, TimelockAction::Upgrade(new_wasm_hash))?;
+        Ok(operation.id)
+    }
+
+    /// Schedule a two-step transfer of the contract admin (only the contract admin).
+    /// Once the timelock delay has passed, `execute_operation` offers the role to
+    /// `new_admin`, who has `ACCEPT_WINDOW_LEDGERS` to call `accept_admin_transfer`.
+    ///
+    /// # Returns
+    /// The ID of the scheduled operation
+    pub fn set_admin(e: &Env, operator: Address, new_admin: Address) -> Result<u64, SpotError> {
+        operator.require_auth();
+        Self::require_contract_admin(e, &operator)?;
+        if new_admin == operator {
+            return Err(SpotError::InvalidParameters);
+        }
+
+        let operation = timelock::schedule(e, TimelockAction::SetAdmin(new_admin))?;
+        Ok(operation.id)
+    }
+
+    /// Schedule a change of the timelock delay (only the contract admin)
+    pub fn set_timelock_delay(e: &Env, operator: Address, delay: u64) -> Result<u64, SpotError> {
+        operator.require_auth();
+        Self::require_contract_admin(e, &operator)?;
+
+        let operation = timelock::schedule(e, TimelockAction::SetDelay(delay))?;
+        Ok(operation.id)
+    }
+
+    /// Execute a timelocked operation whose ETA has passed (only the contract admin)
+    pub fn execute_operation(e: &Env, operator: Address, id: u64) -> Result<(), SpotError> {
+        Self::require_contract_admin(e, &operator)?;
+        let action = timelock::take_ready(e, id)?;
+        // The access control transfer requires the admin's authorization itself
+        if !matches!(action, TimelockAction::SetAdmin(_)) {
+            operator.require_auth();
+        }
+
+        match action {
+            TimelockAction::SetAdmin(new_admin) => {
+                let live_until_ledger = e.ledger().sequence() + ACCEPT_WINDOW_LEDGERS;
+                access_control::transfer_admin_role(e, &new_admin, live_until_ledger);
+            }
+            TimelockAction::GrantAdmin(account) => {
+                access_control::grant_role_no_auth(e, &operator, &account, &symbol_short!("admin"));
+                AdminGranted { account, operator }.publish(e);
+            }
+            TimelockAction::Upgrade(wasm_hash) => {
+                e.deployer().update_current_contract_wasm(wasm_hash.clone());
+                ContractUpgraded { wasm_hash, operator }.publish(e);
+            }
+            TimelockAction::SetDelay(delay) => timelock::set_delay(e, delay)?,
+            // Only factories deploy child contracts
+            TimelockAction::SetEventWasm(_) => return Err(SpotError::InvalidParameters),
+        }
+        Ok(())
+    }
+
+    /// Cancel a pending timelocked operation (any admin, so a second admin key can
+    /// stop operations scheduled with a leaked contract admin key)
+    pub fn cancel_operation(e: &Env, operator: Address, id: u64) -> Result<(), SpotError> {
+        operator.require_auth();
+        Self::require_admin(e, &operator)?;
+
+        timelock::cancel(e, id)?;
+        Ok(())
+    }
+
+    /// List pending timelocked operations
+    pub fn pending_operations(e: &Env) -> Vec<Operation> {
+        timelock::pending(e)
+    }
+
+    /// Current timelock delay in seconds
+    pub fn timelock_delay(e: &Env) -> u64 {
+        timelock::delay(e)
+    }
+
+    /// Migrate storage to the current schema in bounded batches (only admin).
+    /// Call repeatedly until it returns true; progress is kept between calls.
+    ///
+    /// # Arguments
+    /// * `operator` - Admin address
+    /// * `batch_size` - Maximum number of records moved in this call
+    pub fn migrate(e: &Env, operator: Address, batch_size: u32) -> Result<bool, SpotError> {
+        operator.require_auth();
+        Self::require_admin(e, &operator)?;
+        if batch_size == 0 {
+            return Err(SpotError::InvalidParameters);
+        }
+
+        let migrating = migration::schema_version(e) < migration::SCHEMA_VERSION;
+        let done = migration::migrate_batch(e, batch_size);
+        if migrating && done {
+            SchemaMigrated {
+                version: migration::schema_version(e),
+            }
+            .publish(e);
+        }
+        Ok(done)
+    }
+
+    /// Storage schema version of this contract instance
+    pub fn version(e: &Env) -> u32 {
+        migration::schema_version(e)
+    }
+
+    /// Emergency pause of claims, minting, transfers and event creation across every
+    /// event (only admin)
+    ///
+    /// # Arguments
+    /// * `operator` - Admin address
+    /// * `reason` - Why the contract is paused, kept for the audit trail
+    pub fn pause(e: &Env, operator: Address, reason: String) -> Result<(), SpotError> {
+        operator.require_auth();
+        Self::require_admin(e, &operator)?;
+        if reason.is_empty() {
+            return Err(SpotError::InvalidParameters);
+        }
+        Self::require_not_paused(e)?;
+
+        let pause = PauseInfo {
+            reason: reason.clone(),
+            paused_by: operator.clone(),
+            paused_at: e.ledger().timestamp(),
+        };
+        e.storage().instance().set(&DataKey::Paused, &pause);
+
+        PauseChanged {
+            paused: true,
+            operator,
+            reason,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    /// Lift the contract-wide pause (only admin). Event pauses stay in place.
+    pub fn unpause(e: &Env, operator: Address, reason: String) -> Result<(), SpotError> {
+        operator.require_auth();
+        Self::require_admin(e, &operator)?;
+        if reason.is_empty() || Self::paused(e).is_none() {
+            return Err(SpotError::InvalidParameters);
+        }
+
+        e.storage().instance().remove(&DataKey::Paused);
+
+        PauseChanged {
+            paused: false,
+            operator,
+            reason,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    /// Contract-wide pause, if set
+    pub fn paused(e: &Env) -> Option<PauseInfo> {
+        e.storage().instance().get(&DataKey::Paused)
+    }
+
+    /// Emergency pause of claims, minting and transfers of a single event, e.g. when its
+    /// claim codes leak (event creator, event manager or admin)
+    ///
+    /// # Arguments
+    /// * `operator` - Event creator, event manager or admin address
+    /// * `event_id` - Event to pause
+    /// * `reason` - Why the event is paused, kept for the audit trail
+    pub fn pause_event(e: &Env, operator: Address, event_id: u32, reason: String) -> Result<(), SpotError> {
+        operator.require_auth();
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_event_manager(e, &event_data, &operator)?;
+        if reason.is_empty() {
+            return Err(SpotError::InvalidParameters);
+        }
+        if Self::get_event_pause(e, event_id).is_some() {
+            return Err(SpotError::EventPaused);
+        }
+
+        let pause = PauseInfo {
+            reason: reason.clone(),
+            paused_by: operator.clone(),
+            paused_at: e.ledger().timestamp(),
+        };
+        storage::write(e, &DataKey::EventPause(event_id), &pause);
+
+        EventPauseChanged {
+            event_id,
+            paused: true,
+            operator,
+            reason,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    /// Lift the pause of a single event (event creator, event manager or admin)
+    pub fn unpause_event(e: &Env, operator: Address, event_id: u32, reason: String) -> Result<(), SpotError> {
+        operator.require_auth();
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_event_manager(e, &event_data, &operator)?;
+        if reason.is_empty() || Self::get_event_pause(e, event_id).is_none() {
+            return Err(SpotError::InvalidParameters);
+        }
+
+        storage::remove(e, &DataKey::EventPause(event_id));
+
+        EventPauseChanged {
+            event_id,
+            paused: false,
+            operator,
+            reason,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    /// Pause of a single event, if set (see `paused` for the contract-wide pause)
+    pub fn get_event_pause(e: &Env, event_id: u32) -> Option<PauseInfo> {
+        storage::read(e, &DataKey::EventPause(event_id))
+    }
+
+    /// Whether claims of an event are blocked by the contract-wide or the event pause
+    pub fn is_event_paused(e: &Env, event_id: u32) -> bool {
+        Self::require_event_not_paused(e, event_id).is_err()
+    }
+
+    /// Create a new SPOT event in `Draft` status; it becomes claimable once published
     /// 
     /// # Arguments
     /// * `creator` - Address creating the event (must be authorized)
@@ -111,6 +640,7 @@ impl Spot {
         image_url: String,
     ) -> Result<u32, SpotError> {
         creator.require_auth();
+        Self::require_not_paused(e)?;
 
         let is_admin = Self::is_admin_address(e, &creator)?;
         if !is_admin && !Self::has_creator_approval(e, &creator) {
@@ -134,7 +664,7 @@ impl Spot {
         let event_id = event_counter + 1;
 
         // Check if event already exists (shouldn't happen, but safety check)
-        if e.storage().instance().has(&DataKey::EventInfo(event_id)) {
+        if storage::has(e, &DataKey::EventInfo(event_id)) {
             return Err(SpotError::EventAlreadyExists);
         }
 
@@ -151,265 +681,2162 @@ impl Spot {
             claim_end,
             metadata_uri: metadata_uri.clone(),
             image_url: image_url.clone(),
+            allowlist_root: None,
+            status: EventStatus::Draft,
         };
 
+        // Admins are not bound by plans; creators are charged for the event's capacity
+        if !is_admin {
+            let plan = Self::creator_plan(e, &creator)?;
+            Self::charge_plan(e, &creator, plan, event_id, max_poaps, claim_end - claim_start)?;
+            storage::write(e, &DataKey::EventPlan(event_id), &(creator.clone(), plan));
+        }
+
         // Store event information
-        e.storage().instance().set(&DataKey::EventInfo(event_id), &event_data);
-        e.storage().instance().set(&DataKey::EventMintedCount(event_id), &0u32);
+        storage::write(e, &DataKey::EventInfo(event_id), &event_data);
+        storage::write(e, &DataKey::EventMintedCount(event_id), &0u32);
         e.storage().instance().set(&DataKey::EventCounter, &event_id);
+        storage::extend_instance(e);
+
+        EventCreated {
+            event_id,
+            creator,
+            max_poaps,
+            claim_start,
+            claim_end,
+        }
+        .publish(e);
 
         Ok(event_id)
     }
 
     /// Approve a creator after receiving an off-chain payment.
-    /// Grants the creator role and stores payment metadata for audits.
+    /// Grants the creator role, assigns a plan and badge credits, and stores payment
+    /// metadata for audits. Credits are added to any balance the creator already has.
+    /// Re-approving a creator keeps the on-chain payment of their current approval (so
+    /// it stays refundable) and never downgrades their plan.
+    ///
+    /// Without an approver set any admin approves alone. With one, `operator` must be an
+    /// approver and signs the (creator, payment_reference) proposal; every signer must
+    /// agree on plan and credits, and the approval is applied on the threshold signature.
+    ///
+    /// # Returns
+    /// True if the creator was approved, false while the proposal awaits signatures
     pub fn approve_creator(
         e: &Env,
         operator: Address,
         creator: Address,
         payment_reference: String,
-    ) -> Result<(), SpotError> {
-        Self::require_admin(e, &operator)?;
-        access_control::grant_role(e, &operator, &creator, &symbol_short!("creator"));
+        plan: Plan,
+        credits: u32,
+    ) -> Result<bool, SpotError> {
+        operator.require_auth();
+        if plan == Plan::Free && credits > 0 {
+            return Err(SpotError::InvalidParameters);
+        }
+        match multisig::approver_set(e) {
+            None => Self::require_admin(e, &operator)?,
+            Some(set) => {
+                let key = DataKey::CreatorProposal(creator.clone(), payment_reference.clone());
+                let mut proposal: CreatorProposal = storage::read(e, &key).unwrap_or(CreatorProposal {
+                    plan,
+                    credits,
+                    signers: Vec::new(e),
+                });
+                if proposal.plan != plan || proposal.credits != credits {
+                    return Err(SpotError::InvalidParameters);
+                }
+                let signatures = multisig::sign(&set, &mut proposal.signers, &operator)?;
 
+                CreatorApprovalSigned {
+                    creator: creator.clone(),
+                    approver: operator.clone(),
+                    payment_reference: payment_reference.clone(),
+                    signatures,
+                    threshold: set.threshold,
+                }
+                .publish(e);
+
+                if signatures < set.threshold {
+                    storage::write(e, &key, &proposal);
+                    return Ok(false);
+                }
+                storage::remove(e, &key);
+            }
+        }
+        access_control::grant_role_no_auth(e, &operator, &creator, &symbol_short!("creator"));
+
+        let current: Option<CreatorApproval> = storage::read(e, &DataKey::CreatorApproval(creator.clone()));
+        let (plan, payment) = match current {
+            Some(current) => (plan.max(current.plan), current.payment),
+            None => (plan, None),
+        };
         let approval = CreatorApproval {
-            payment_reference,
+            payment_reference: payment_reference.clone(),
             approved_at: e.ledger().timestamp(),
             approved_by: operator.clone(),
+            plan,
+            payment,
         };
 
-        e.storage()
-            .instance()
-            .set(&DataKey::CreatorApproval(creator), &approval);
+        storage::write(e, &DataKey::CreatorApproval(creator.clone()), &approval);
+        Self::add_credits(e, &creator, credits);
 
-        Ok(())
+        CreatorApproved {
+            creator,
+            operator,
+            payment_reference,
+            plan,
+            credits,
+        }
+        .publish(e);
+
+        Ok(true)
     }
 
-    /// Remove approval and creator role, e.g. when a refund is issued.
-    pub fn revoke_creator_approval(
+    /// Configure the approvers for M-of-N creator onboarding (only the contract admin,
+    /// and only once). Afterwards the set changes through `propose_approver_change`.
+    ///
+    /// # Arguments
+    /// * `operator` - Contract admin address
+    /// * `approvers` - Approver addresses (at most `MAX_APPROVERS`, no duplicates)
+    /// * `threshold` - Signatures required, between 1 and the number of approvers
+    pub fn set_approvers(
         e: &Env,
         operator: Address,
-        creator: Address,
+        approvers: Vec<Address>,
+        threshold: u32,
     ) -> Result<(), SpotError> {
-        Self::require_admin(e, &operator)?;
-        access_control::revoke_role(e, &operator, &creator, &symbol_short!("creator"));
-        e.storage().instance().remove(&DataKey::CreatorApproval(creator));
-        Ok(())
-    }
+        operator.require_auth();
+        Self::require_contract_admin(e, &operator)?;
+        if multisig::approver_set(e).is_some() {
+            return Err(SpotError::Unauthorized);
+        }
 
-    /// Retrieve payment approval metadata for a creator.
-    pub fn get_creator_approval(e: &Env, creator: Address) -> Option<CreatorApproval> {
-        e.storage().instance().get(&DataKey::CreatorApproval(creator))
+        let set = ApproverSet { approvers, threshold };
+        multisig::validate(&set)?;
+        storage::write(e, &DataKey::Approvers, &set);
+
+        ApproversUpdated {
+            approvers: set.approvers,
+            threshold,
+        }
+        .publish(e);
+        Ok(())
     }
 
-    /// Claim a SPOT badge for a specific event
-    /// 
-    /// # Arguments
-    /// * `event_id` - ID of the event
-    /// * `to` - Address that will receive the SPOT NFT
-    /// 
+    /// Sign a change to the approver set (only approvers). The change is applied on the
+    /// threshold signature; it must leave a valid set.
+    ///
     /// # Returns
-    /// The token ID of the minted SPOT badge
-    pub fn claim(e: &Env, event_id: u32, to: Address) -> Result<u32, SpotError> {
-        // Get event information
-        let event_data: EventData = e.storage().instance().get(&DataKey::EventInfo(event_id))
-            .ok_or(SpotError::EventNotFound)?;
+    /// True if the change was applied, false while it awaits signatures
+    pub fn propose_approver_change(
+        e: &Env,
+        operator: Address,
+        change: ApproverChange,
+    ) -> Result<bool, SpotError> {
+        operator.require_auth();
+        let set = multisig::approver_set(e).ok_or(SpotError::Unauthorized)?;
+        let mut updated = set.clone();
+        multisig::apply_change(&mut updated, &change)?;
 
-        // Check if claim period is active
-        let current_time = e.ledger().timestamp();
-        if current_time < event_data.claim_start {
-            return Err(SpotError::ClaimPeriodNotStarted);
+        let key = DataKey::ApproverProposal(change.clone());
+        let mut signers: Vec<Address> = storage::read(e, &key).unwrap_or(Vec::new(e));
+        let signatures = multisig::sign(&set, &mut signers, &operator)?;
+
+        ApproverChangeSigned {
+            change,
+            approver: operator,
+            signatures,
+            threshold: set.threshold,
         }
-        if current_time > event_data.claim_end {
-            return Err(SpotError::ClaimPeriodEnded);
+        .publish(e);
+
+        if signatures < set.threshold {
+            storage::write(e, &key, &signers);
+            return Ok(false);
         }
+        storage::remove(e, &key);
+        storage::write(e, &DataKey::Approvers, &updated);
 
-        // Check if address has already claimed (prevent duplicates)
-        if e.storage().instance().has(&DataKey::HasClaimed(event_id, to.clone())) {
-            return Err(SpotError::AlreadyClaimed);
+        ApproversUpdated {
+            approvers: updated.approvers,
+            threshold: updated.threshold,
         }
+        .publish(e);
+        Ok(true)
+    }
 
-        // Check if limit is exceeded
-        let minted: u32 = e.storage().instance().get(&DataKey::EventMintedCount(event_id))
-            .unwrap_or(0u32);
+    /// Cancel a pending creator approval proposal (only approvers), e.g. when its payment
+    /// was refunded or signed with the wrong plan or credits. Its signatures are discarded.
+    pub fn cancel_creator_proposal(
+        e: &Env,
+        operator: Address,
+        creator: Address,
+        payment_reference: String,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+        let set = multisig::approver_set(e).ok_or(SpotError::Unauthorized)?;
+        if !set.approvers.contains(&operator) {
+            return Err(SpotError::Unauthorized);
+        }
 
-        if minted >= event_data.max_poaps {
-            return Err(SpotError::LimitExceeded);
+        let key = DataKey::CreatorProposal(creator.clone(), payment_reference.clone());
+        if !storage::has(e, &key) {
+            return Err(SpotError::ProposalNotFound);
         }
+        storage::remove(e, &key);
 
-        // Mint the NFT
-        let token_id = Enumerable::sequential_mint(e, &to);
+        CreatorProposalCancelled {
+            creator,
+            payment_reference,
+            approver: operator,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    /// Get the approver set, if M-of-N onboarding is configured
+    pub fn get_approvers(e: &Env) -> Option<ApproverSet> {
+        multisig::approver_set(e)
+    }
+
+    /// Get a pending creator approval proposal
+    pub fn get_creator_proposal(
+        e: &Env,
+        creator: Address,
+        payment_reference: String,
+    ) -> Option<CreatorProposal> {
+        storage::read(e, &DataKey::CreatorProposal(creator, payment_reference))
+    }
+
+    /// Signers of a pending change to the approver set
+    pub fn get_approver_proposal(e: &Env, change: ApproverChange) -> Vec<Address> {
+        storage::read(e, &DataKey::ApproverProposal(change)).unwrap_or(Vec::new(e))
+    }
+
+    /// Set or clear the self-service approval price of a plan in a token (only admin)
+    ///
+    /// # Arguments
+    /// * `operator` - Admin address
+    /// * `token` - Stellar Asset Contract accepted as payment (e.g. XLM or USDC)
+    /// * `plan` - Plan being sold
+    /// * `price` - Amount (in the token's smallest unit) and included credits, or None to stop selling
+    pub fn set_approval_price(
+        e: &Env,
+        operator: Address,
+        token: Address,
+        plan: Plan,
+        price: Option<ApprovalPrice>,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+        Self::require_admin(e, &operator)?;
+
+        let key = DataKey::ApprovalPrice(token.clone(), plan);
+        match price {
+            Some(price) => {
+                if price.amount <= 0 || (plan == Plan::Free && price.credits > 0) {
+                    return Err(SpotError::InvalidParameters);
+                }
+                storage::write(e, &key, &price);
+            }
+            None => storage::remove(e, &key),
+        }
+
+        ApprovalPriceUpdated { token, plan, operator }.publish(e);
+        Ok(())
+    }
+
+    /// Get the self-service approval price of a plan in a token
+    pub fn get_approval_price(e: &Env, token: Address, plan: Plan) -> Option<ApprovalPrice> {
+        storage::read(e, &DataKey::ApprovalPrice(token, plan))
+    }
+
+    /// Self-service approval: the creator pays the configured price into the contract
+    /// treasury and is approved for `plan` with the price's included credits. Creators
+    /// that are already approved must be revoked before purchasing again.
+    ///
+    /// # Arguments
+    /// * `creator` - Address paying and being approved (must authorize)
+    /// * `token` - Stellar Asset Contract to pay with
+    /// * `plan` - Plan to purchase
+    pub fn purchase_approval(
+        e: &Env,
+        creator: Address,
+        token: Address,
+        plan: Plan,
+    ) -> Result<(), SpotError> {
+        creator.require_auth();
+        if Self::has_creator_approval(e, &creator) {
+            return Err(SpotError::CreatorAlreadyApproved);
+        }
+        let price = Self::get_approval_price(e, token.clone(), plan).ok_or(SpotError::PriceNotSet)?;
+
+        token::Client::new(e, &token).transfer(&creator, &e.current_contract_address(), &price.amount);
+
+        let contract = e.current_contract_address();
+        access_control::grant_role_no_auth(e, &contract, &creator, &symbol_short!("creator"));
+
+        let approval = CreatorApproval {
+            payment_reference: String::from_str(e, "on-chain"),
+            approved_at: e.ledger().timestamp(),
+            approved_by: creator.clone(),
+            plan,
+            payment: Some(CreatorPayment {
+                token: token.clone(),
+                amount: price.amount,
+                credits: price.credits,
+            }),
+        };
+        storage::write(e, &DataKey::CreatorApproval(creator.clone()), &approval);
+        Self::add_credits(e, &creator, price.credits);
+
+        ApprovalPurchased {
+            creator,
+            token,
+            amount: price.amount,
+            plan,
+            credits: price.credits,
+        }
+        .publish(e);
+
+        Ok(())
+    }
+
+    /// Remove approval and creator role. With `refund`, the share of the on-chain payment
+    /// matching the credits still unspent is returned to the creator from the treasury and
+    /// their credits are cleared. Credits already charged to events are not refunded.
+    pub fn revoke_creator_approval(
+        e: &Env,
+        operator: Address,
+        creator: Address,
+        refund: bool,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+        Self::require_admin(e, &operator)?;
+
+        let approval = Self::get_creator_approval(e, creator.clone());
+        let mut refunded = 0i128;
+        if refund {
+            let payment = approval
+                .and_then(|approval| approval.payment)
+                .ok_or(SpotError::InvalidParameters)?;
+            refunded = if payment.credits == 0 {
+                payment.amount
+            } else {
+                let unspent = Self::get_credits(e, creator.clone()).min(payment.credits);
+                payment.amount * unspent as i128 / payment.credits as i128
+            };
+            if refunded > 0 {
+                token::Client::new(e, &payment.token).transfer(
+                    &e.current_contract_address(),
+                    &creator,
+                    &refunded,
+                );
+            }
+            storage::remove(e, &DataKey::CreatorCredits(creator.clone()));
+        }
+
+        access_control::revoke_role_no_auth(e, &operator, &creator, &symbol_short!("creator"));
+        storage::remove(e, &DataKey::CreatorApproval(creator.clone()));
+
+        CreatorRevoked {
+            creator,
+            operator,
+            refunded,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    /// Configure or disable donations in a token (only admin)
+    ///
+    /// Each tier names a collection of the donor event; tier badges are minted from the
+    /// collection's reserved supply.
+    pub fn set_donation_config(
+        e: &Env,
+        operator: Address,
+        token: Address,
+        config: Option<DonationConfig>,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+        Self::require_admin(e, &operator)?;
+
+        let key = DataKey::DonationConfig(token.clone());
+        let event_id = config.as_ref().map(|config| config.event_id);
+        match config {
+            Some(config) => {
+                Self::get_event(e, config.event_id)?;
+                if config.tiers.is_empty() || config.tiers.len() > MAX_COLLECTIONS {
+                    return Err(SpotError::InvalidParameters);
+                }
+                for tier in config.tiers.iter() {
+                    if tier.min_total <= 0 {
+                        return Err(SpotError::InvalidParameters);
+                    }
+                    Self::get_collection(e, config.event_id, tier.collection_id)?;
+                }
+                storage::write(e, &key, &config);
+            }
+            None => storage::remove(e, &key),
+        }
+
+        DonationConfigUpdated { token, event_id, operator }.publish(e);
+        Ok(())
+    }
+
+    /// Get the donation configuration of a token
+    pub fn get_donation_config(e: &Env, token: Address) -> Option<DonationConfig> {
+        storage::read(e, &DataKey::DonationConfig(token))
+    }
+
+    /// Donate to the project. The amount is transferred into the contract treasury and
+    /// the donor automatically receives the badge of every tier their cumulative total
+    /// reaches (while the tier's reserved supply lasts). While the donor event is not
+    /// published or is paused the donation is still accepted and counted, but no badge
+    /// is minted; the donor receives the badges they reached on their next donation.
+    ///
+    /// # Arguments
+    /// * `donor` - Donor address (must authorize)
+    /// * `token` - Stellar Asset Contract to donate in (e.g. XLM or USDC)
+    /// * `amount` - Amount in the token's smallest unit
+    ///
+    /// # Returns
+    /// Token IDs of the donor badges minted by this donation
+    pub fn donate(e: &Env, donor: Address, token: Address, amount: i128) -> Result<Vec<u32>, SpotError> {
+        donor.require_auth();
+        if amount <= 0 {
+            return Err(SpotError::InvalidParameters);
+        }
+        let config = Self::get_donation_config(e, token.clone()).ok_or(SpotError::DonationsDisabled)?;
+        let event_data = Self::get_event(e, config.event_id)?;
+
+        token::Client::new(e, &token).transfer(&donor, &e.current_contract_address(), &amount);
+
+        let total_key = DataKey::DonorTotal(donor.clone(), token.clone());
+        let total = Self::get_donor_total(e, donor.clone(), token.clone())
+            .checked_add(amount)
+            .ok_or(SpotError::InvalidParameters)?;
+        storage::write(e, &total_key, &total);
+
+        let mut badges = Vec::new(e);
+        let badges_open = event_data.status == EventStatus::Published
+            && Self::require_event_not_paused(e, event_data.event_id).is_ok();
+        if badges_open {
+            for tier in config.tiers.iter() {
+                if total < tier.min_total
+                    || Self::has_claimed_collection(e, event_data.event_id, tier.collection_id, donor.clone())
+                {
+                    continue;
+                }
+                let mut collection = Self::get_collection(e, event_data.event_id, tier.collection_id)?;
+                if collection.reserved_minted >= collection.reserved_supply {
+                    continue;
+                }
+                let token_id = Self::mint_collection_badge(e, &event_data, tier.collection_id, donor.clone())?;
+                collection.reserved_minted += 1;
+                storage::write(e, &DataKey::Collection(event_data.event_id, tier.collection_id), &collection);
+                badges.push_back(token_id);
+            }
+        }
+
+        DonationReceived {
+            donor,
+            token,
+            amount,
+            total,
+        }
+        .publish(e);
+
+        Ok(badges)
+    }
+
+    /// Cumulative donations of a donor in a token
+    pub fn get_donor_total(e: &Env, donor: Address, token: Address) -> i128 {
+        storage::read(e, &DataKey::DonorTotal(donor, token)).unwrap_or(0i128)
+    }
+
+    /// Move funds (approval payments and donations) out of the contract treasury (only admin)
+    pub fn withdraw_treasury(
+        e: &Env,
+        operator: Address,
+        token: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+        Self::require_admin(e, &operator)?;
+        if amount <= 0 {
+            return Err(SpotError::InvalidParameters);
+        }
+
+        token::Client::new(e, &token).transfer(&e.current_contract_address(), &to, &amount);
+
+        TreasuryWithdrawn {
+            token,
+            to,
+            amount,
+            operator,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    /// Badge credits available to a creator
+    pub fn get_credits(e: &Env, creator: Address) -> u32 {
+        storage::read(e, &DataKey::CreatorCredits(creator)).unwrap_or(0u32)
+    }
+
+    /// Retrieve payment approval metadata for a creator.
+    pub fn get_creator_approval(e: &Env, creator: Address) -> Option<CreatorApproval> {
+        storage::read(e, &DataKey::CreatorApproval(creator))
+    }
+
+    /// Claim a SPOT badge for a specific event
+    /// 
+    /// # Arguments
+    /// * `event_id` - ID of the event
+    /// * `to` - Address that will receive the SPOT NFT (must authorize)
+    /// 
+    /// # Returns
+    /// The token ID of the minted SPOT badge
+    pub fn claim(e: &Env, event_id: u32, to: Address) -> Result<u32, SpotError> {
+        to.require_auth();
+
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_open_claim(e, &event_data)?;
+
+        Self::mint_claim(e, &event_data, to)
+    }
+
+    /// Claim a SPOT badge on behalf of an attendee (sponsored claim).
+    /// The operator must be the event creator, an admin or an event minter/manager and
+    /// pays the fees; claim window, duplicate and supply rules still apply.
+    /// 
+    /// # Arguments
+    /// * `operator` - Event creator, admin or event minter sponsoring the claim (must authorize)
+    /// * `event_id` - ID of the event
+    /// * `to` - Address that will receive the SPOT NFT
+    /// 
+    /// # Returns
+    /// The token ID of the minted SPOT badge
+    pub fn claim_sponsored(e: &Env, operator: Address, event_id: u32, to: Address) -> Result<u32, SpotError> {
+        operator.require_auth();
+
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_event_minter(e, &event_data, &operator)?;
+
+        Self::mint_claim(e, &event_data, to)
+    }
+
+    /// Mint badges for a list of attendees in one transaction (event creator, admin or
+    /// event minter). Recipients that already claimed, or that come after `max_poaps` is
+    /// reached, are skipped; the claim window and claim-method restrictions do not apply.
+    ///
+    /// # Arguments
+    /// * `event_id` - ID of the event
+    /// * `operator` - Event creator, admin or event minter (must authorize)
+    /// * `recipients` - Attendee addresses (at most `MAX_AIRDROP_BATCH`)
+    ///
+    /// # Returns
+    /// One `AirdropResult` per recipient, in order
+    pub fn airdrop(
+        e: &Env,
+        event_id: u32,
+        operator: Address,
+        recipients: Vec<Address>,
+    ) -> Result<Vec<AirdropResult>, SpotError> {
+        operator.require_auth();
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_event_minter(e, &event_data, &operator)?;
+
+        if recipients.is_empty() || recipients.len() > MAX_AIRDROP_BATCH {
+            return Err(SpotError::InvalidParameters);
+        }
+        if event_data.status != EventStatus::Published {
+            return Err(SpotError::InvalidEventStatus);
+        }
+        Self::require_event_not_paused(e, event_id)?;
+
+        // The minted counter and instance TTL are written once for the whole batch
+        let mut minted: u32 = storage::read(e, &DataKey::EventMintedCount(event_id))
+            .unwrap_or(0u32);
+        let mut results = Vec::new(e);
+        for to in recipients.iter() {
+            let result = if Self::has_claim_record(e, event_id, &to) {
+                AirdropResult::AlreadyClaimed
+            } else if minted >= event_data.max_poaps {
+                AirdropResult::LimitExceeded
+            } else {
+                let token_id = Self::mint_badge(e, event_id, minted, to);
+                minted += 1;
+                AirdropResult::Minted(token_id)
+            };
+            results.push_back(result);
+        }
+
+        storage::write(e, &DataKey::EventMintedCount(event_id), &minted);
+        storage::extend_instance(e);
+
+        Ok(results)
+    }
+
+    /// Set or clear the allowlist Merkle root of an event (event creator, event manager or admin)
+    ///
+    /// Leaves are `sha256(xdr(address))`; parent nodes hash the sorted pair of children.
+    pub fn set_allowlist_root(
+        e: &Env,
+        operator: Address,
+        event_id: u32,
+        root: Option<BytesN<32>>,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+        let mut event_data = Self::get_event(e, event_id)?;
+        Self::require_event_manager(e, &event_data, &operator)?;
+
+        event_data.allowlist_root = root;
+        storage::write(e, &DataKey::EventInfo(event_id), &event_data);
+
+        EventUpdated { event_id, operator }.publish(e);
+        Ok(())
+    }
+
+    /// Claim a SPOT badge for an invite-only event by proving `to` is in its allowlist
+    /// 
+    /// # Arguments
+    /// * `event_id` - ID of the event
+    /// * `to` - Address that will receive the SPOT NFT (must authorize)
+    /// * `proof` - Sibling hashes from the leaf of `to` up to the allowlist root
+    /// 
+    /// # Returns
+    /// The token ID of the minted SPOT badge
+    pub fn claim_allowlisted(
+        e: &Env,
+        event_id: u32,
+        to: Address,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<u32, SpotError> {
+        to.require_auth();
+
+        let event_data = Self::get_event(e, event_id)?;
+        let root = event_data
+            .allowlist_root
+            .clone()
+            .ok_or(SpotError::NotAllowlisted)?;
+
+        let mut node: BytesN<32> = e.crypto().sha256(&to.clone().to_xdr(e)).into();
+        for sibling in proof.iter() {
+            let mut pair = Bytes::new(e);
+            if node.to_array() <= sibling.to_array() {
+                pair.append(&node.into());
+                pair.append(&sibling.into());
+            } else {
+                pair.append(&sibling.into());
+                pair.append(&node.into());
+            }
+            node = e.crypto().sha256(&pair).into();
+        }
+
+        if node != root {
+            return Err(SpotError::NotAllowlisted);
+        }
+
+        Self::mint_claim(e, &event_data, to)
+    }
+
+    /// Register the ed25519 public key that signs claim vouchers (QR method) for an event
+    /// (event creator, event manager or admin)
+    pub fn set_voucher_issuer(
+        e: &Env,
+        operator: Address,
+        event_id: u32,
+        public_key: BytesN<32>,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+        let event_data: EventData = storage::read(e, &DataKey::EventInfo(event_id))
+            .ok_or(SpotError::EventNotFound)?;
+        Self::require_event_manager(e, &event_data, &operator)?;
+
+        storage::write(e, &DataKey::VoucherIssuer(event_id), &public_key);
+        EventUpdated { event_id, operator }.publish(e);
+        Ok(())
+    }
+
+    /// Get the voucher issuer public key registered for an event
+    pub fn get_voucher_issuer(e: &Env, event_id: u32) -> Option<BytesN<32>> {
+        storage::read(e, &DataKey::VoucherIssuer(event_id))
+    }
+
+    /// Get the exact payload an issuer must sign for a voucher
+    /// (XDR of the tuple `(contract_address, voucher)`)
+    pub fn voucher_payload(e: &Env, voucher: ClaimVoucher) -> Bytes {
+        (e.current_contract_address(), voucher).to_xdr(e)
+    }
+
+    /// Claim a SPOT badge with a voucher signed by the event's issuer key
+    /// 
+    /// # Arguments
+    /// * `event_id` - ID of the event
+    /// * `to` - Address that will receive the SPOT NFT (must authorize)
+    /// * `voucher` - Voucher contents (event ID, recipient or wildcard, expiry, nonce)
+    /// * `signature` - ed25519 signature over `voucher_payload(voucher)`
+    /// 
+    /// # Returns
+    /// The token ID of the minted SPOT badge
+    pub fn claim_with_voucher(
+        e: &Env,
+        event_id: u32,
+        to: Address,
+        voucher: ClaimVoucher,
+        signature: BytesN<64>,
+    ) -> Result<u32, SpotError> {
+        to.require_auth();
+
+        if voucher.event_id != event_id {
+            return Err(SpotError::InvalidVoucher);
+        }
+        if let Some(recipient) = voucher.recipient.clone() {
+            if recipient != to {
+                return Err(SpotError::InvalidVoucher);
+            }
+        }
+        if e.ledger().timestamp() > voucher.expires_at {
+            return Err(SpotError::VoucherExpired);
+        }
+
+        let nonce_key = DataKey::VoucherNonce(event_id, voucher.nonce);
+        if e.storage().persistent().has(&nonce_key) {
+            return Err(SpotError::VoucherAlreadyUsed);
+        }
+
+        let issuer: BytesN<32> = storage::read(e, &DataKey::VoucherIssuer(event_id))
+            .ok_or(SpotError::InvalidVoucher)?;
+
+        // Panics (and aborts the claim) if the signature does not match
+        let payload = Self::voucher_payload(e, voucher);
+        e.crypto().ed25519_verify(&issuer, &payload, &signature);
+
+        storage::write(e, &nonce_key, &true);
+
+        let event_data = Self::get_event(e, event_id)?;
+        Self::mint_claim(e, &event_data, to)
+    }
+
+    /// Register a shared claim code for an event, replacing any previous one
+    /// (event creator, event manager or admin)
+    ///
+    /// # Arguments
+    /// * `operator` - Event creator, event manager or admin
+    /// * `event_id` - ID of the event
+    /// * `code_hash` - sha256 of the shared code
+    /// * `max_uses` - Number of claims the code allows
+    /// * `require_commit` - Only accept the code through `commit_claim` + `reveal_claim`
+    pub fn set_claim_code(
+        e: &Env,
+        operator: Address,
+        event_id: u32,
+        code_hash: BytesN<32>,
+        max_uses: u32,
+        require_commit: bool,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_event_manager(e, &event_data, &operator)?;
+
+        if max_uses == 0 {
+            return Err(SpotError::InvalidParameters);
+        }
+
+        let claim_code = ClaimCode {
+            code_hash,
+            uses_remaining: max_uses,
+            require_commit,
+        };
+        storage::write(e, &DataKey::ClaimCode(event_id), &claim_code);
+        EventUpdated { event_id, operator }.publish(e);
+        Ok(())
+    }
+
+    /// Get the number of remaining uses of an event's shared claim code
+    pub fn claim_code_uses(e: &Env, event_id: u32) -> u32 {
+        storage::read::<ClaimCode>(e, &DataKey::ClaimCode(event_id))
+            .map(|claim_code| claim_code.uses_remaining)
+            .unwrap_or(0u32)
+    }
+
+    /// Claim a SPOT badge with the event's shared claim code
+    /// 
+    /// # Arguments
+    /// * `event_id` - ID of the event
+    /// * `to` - Address that will receive the SPOT NFT (must authorize)
+    /// * `code` - Preimage of the registered code hash
+    /// 
+    /// # Returns
+    /// The token ID of the minted SPOT badge
+    pub fn claim_with_code(e: &Env, event_id: u32, to: Address, code: Bytes) -> Result<u32, SpotError> {
+        to.require_auth();
+        Self::redeem_claim_code(e, event_id, to, code, false)
+    }
+
+    /// Commit to a secret claim code or link ID before revealing it (phase one of
+    /// commit–reveal)
+    ///
+    /// `commitment` is `sha256(secret || xdr(to) || salt)`. The reveal (`reveal_claim` for
+    /// codes, `reveal_link` for links) must happen in a later ledger and within
+    /// `REVEAL_WINDOW_LEDGERS`; expired commitments are dropped automatically from
+    /// temporary storage.
+    pub fn commit_claim(e: &Env, event_id: u32, commitment: BytesN<32>) -> Result<(), SpotError> {
+        Self::get_event(e, event_id)?;
+
+        let key = DataKey::ClaimCommit(event_id, commitment.clone());
+        if e.storage().temporary().has(&key) {
+            return Err(SpotError::InvalidCommitment);
+        }
+
+        e.storage().temporary().set(&key, &e.ledger().sequence());
+        e.storage()
+            .temporary()
+            .extend_ttl(&key, REVEAL_WINDOW_LEDGERS, REVEAL_WINDOW_LEDGERS);
+
+        ClaimCommitted { event_id, commitment }.publish(e);
+        Ok(())
+    }
+
+    /// Reveal a committed claim code and claim a SPOT badge (phase two of commit–reveal)
+    /// 
+    /// # Arguments
+    /// * `event_id` - ID of the event
+    /// * `to` - Address that will receive the SPOT NFT (must authorize)
+    /// * `secret` - The shared claim code
+    /// * `salt` - Salt used when building the commitment
+    /// 
+    /// # Returns
+    /// The token ID of the minted SPOT badge
+    pub fn reveal_claim(
+        e: &Env,
+        event_id: u32,
+        to: Address,
+        secret: Bytes,
+        salt: BytesN<32>,
+    ) -> Result<u32, SpotError> {
+        to.require_auth();
+        Self::take_commitment(e, event_id, &to, &secret, salt)?;
+
+        Self::redeem_claim_code(e, event_id, to, secret, true)
+    }
+
+    /// Register a batch of one-time claim links for an event (event creator, event
+    /// minter/manager or admin)
+    ///
+    /// # Arguments
+    /// * `operator` - Event creator, event minter/manager or admin
+    /// * `event_id` - ID of the event
+    /// * `link_hashes` - sha256 of each link ID (at most `MAX_LINK_BATCH`)
+    /// * `expires_at` - Expiry of the whole batch (at most one week from now)
+    pub fn register_links(
+        e: &Env,
+        operator: Address,
+        event_id: u32,
+        link_hashes: Vec<BytesN<32>>,
+        expires_at: u64,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_event_minter(e, &event_data, &operator)?;
+
+        let now = e.ledger().timestamp();
+        if link_hashes.is_empty()
+            || link_hashes.len() > MAX_LINK_BATCH
+            || expires_at <= now
+            || expires_at > now + MAX_LINK_VALIDITY
+        {
+            return Err(SpotError::InvalidParameters);
+        }
+
+        let link = ClaimLink {
+            expires_at,
+            status: LinkStatus::Active,
+        };
+        for link_hash in link_hashes.iter() {
+            let key = DataKey::ClaimLink(event_id, link_hash);
+            // A link ID can only ever be registered once, even after it was used
+            if e.storage().persistent().has(&key) {
+                return Err(SpotError::InvalidClaimLink);
+            }
+            storage::write(e, &key, &link);
+        }
+
+        let remaining = Self::links_remaining(e, event_id);
+        storage::write(
+            e,
+            &DataKey::LinksRemaining(event_id),
+            &(remaining + link_hashes.len()),
+        );
+        EventUpdated { event_id, operator }.publish(e);
+        Ok(())
+    }
+
+    /// Revoke unused claim links of an event (event creator, event manager or admin)
+    pub fn revoke_links(
+        e: &Env,
+        operator: Address,
+        event_id: u32,
+        link_hashes: Vec<BytesN<32>>,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_event_manager(e, &event_data, &operator)?;
+
+        if link_hashes.len() > MAX_LINK_BATCH {
+            return Err(SpotError::InvalidParameters);
+        }
+
+        let mut revoked = 0u32;
+        for link_hash in link_hashes.iter() {
+            let key = DataKey::ClaimLink(event_id, link_hash);
+            if let Some(mut link) = storage::read::<ClaimLink>(e, &key) {
+                if link.status == LinkStatus::Active {
+                    link.status = LinkStatus::Revoked;
+                    storage::write(e, &key, &link);
+                    revoked += 1;
+                }
+            }
+        }
+
+        let remaining = Self::links_remaining(e, event_id);
+        storage::write(
+            e,
+            &DataKey::LinksRemaining(event_id),
+            &remaining.saturating_sub(revoked),
+        );
+        EventUpdated { event_id, operator }.publish(e);
+        Ok(())
+    }
+
+    /// Get the number of registered links of an event that were neither used nor revoked
+    /// (expired links are included until revoked)
+    pub fn links_remaining(e: &Env, event_id: u32) -> u32 {
+        storage::read(e, &DataKey::LinksRemaining(event_id)).unwrap_or(0u32)
+    }
+
+    /// Reveal a committed link ID and claim a SPOT badge with it (phase two of
+    /// commit–reveal). Links are only accepted this way, so a link ID seen in a pending
+    /// transaction cannot be claimed by someone else.
+    /// 
+    /// # Arguments
+    /// * `event_id` - ID of the event
+    /// * `to` - Address that will receive the SPOT NFT (must authorize)
+    /// * `link_id` - Secret link ID; its sha256 must be registered for the event
+    /// * `salt` - Salt used when building the commitment
+    /// 
+    /// # Returns
+    /// The token ID of the minted SPOT badge
+    pub fn reveal_link(
+        e: &Env,
+        event_id: u32,
+        to: Address,
+        link_id: Bytes,
+        salt: BytesN<32>,
+    ) -> Result<u32, SpotError> {
+        to.require_auth();
+        Self::take_commitment(e, event_id, &to, &link_id, salt)?;
+
+        let event_data = Self::get_event(e, event_id)?;
+        let key = DataKey::ClaimLink(event_id, e.crypto().sha256(&link_id).into());
+        let mut link: ClaimLink = storage::read(e, &key).ok_or(SpotError::InvalidClaimLink)?;
+
+        if link.status != LinkStatus::Active {
+            return Err(SpotError::InvalidClaimLink);
+        }
+        if e.ledger().timestamp() > link.expires_at {
+            return Err(SpotError::ClaimLinkExpired);
+        }
+
+        let token_id = Self::mint_claim(e, &event_data, to)?;
+
+        link.status = LinkStatus::Used;
+        storage::write(e, &key, &link);
+        let remaining = Self::links_remaining(e, event_id);
+        storage::write(e, &DataKey::LinksRemaining(event_id), &remaining.saturating_sub(1));
+
+        Ok(token_id)
+    }
+
+    /// Register NFC tag public keys for an event (event creator, event manager or admin)
+    pub fn register_nfc_tags(
+        e: &Env,
+        operator: Address,
+        event_id: u32,
+        public_keys: Vec<BytesN<32>>,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_event_manager(e, &event_data, &operator)?;
+
+        if public_keys.is_empty() || public_keys.len() > MAX_NFC_BATCH {
+            return Err(SpotError::InvalidParameters);
+        }
+
+        let mut count = Self::nfc_tag_count(e, event_id);
+        for public_key in public_keys.iter() {
+            let key = DataKey::NfcTag(event_id, public_key);
+            if !e.storage().persistent().has(&key) {
+                count += 1;
+            }
+            storage::write(e, &key, &true);
+        }
+        storage::write(e, &DataKey::NfcTagCount(event_id), &count);
+        EventUpdated { event_id, operator }.publish(e);
+        Ok(())
+    }
+
+    /// Remove an NFC tag from an event, e.g. when a tag is lost (event creator, event manager or admin)
+    pub fn revoke_nfc_tag(
+        e: &Env,
+        operator: Address,
+        event_id: u32,
+        public_key: BytesN<32>,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_event_manager(e, &event_data, &operator)?;
+
+        let key = DataKey::NfcTag(event_id, public_key);
+        if e.storage().persistent().has(&key) {
+            storage::remove(e, &key);
+            let count = Self::nfc_tag_count(e, event_id);
+            storage::write(e, &DataKey::NfcTagCount(event_id), &count.saturating_sub(1));
+        }
+        EventUpdated { event_id, operator }.publish(e);
+        Ok(())
+    }
+
+    /// Number of NFC tags registered for an event
+    pub fn nfc_tag_count(e: &Env, event_id: u32) -> u32 {
+        storage::read(e, &DataKey::NfcTagCount(event_id)).unwrap_or(0u32)
+    }
+
+    /// Check whether an NFC tag is registered for an event
+    pub fn is_nfc_tag(e: &Env, event_id: u32, public_key: BytesN<32>) -> bool {
+        e.storage().persistent().has(&DataKey::NfcTag(event_id, public_key))
+    }
+
+    /// Get the exact challenge an NFC tag must sign
+    /// (XDR of the tuple `(contract_address, event_id, claimer, nonce_ledger)`)
+    pub fn nfc_payload(e: &Env, event_id: u32, claimer: Address, nonce_ledger: u32) -> Bytes {
+        (e.current_contract_address(), event_id, claimer, nonce_ledger).to_xdr(e)
+    }
+
+    /// Claim a SPOT badge by tapping a registered NFC tag
+    /// 
+    /// # Arguments
+    /// * `event_id` - ID of the event
+    /// * `to` - Address that will receive the SPOT NFT (must authorize)
+    /// * `tag_key` - Public key of the NFC tag
+    /// * `nonce_ledger` - Recent ledger sequence used as the challenge nonce
+    /// * `signature` - Tag signature over `nfc_payload(event_id, to, nonce_ledger)`
+    /// 
+    /// # Returns
+    /// The token ID of the minted SPOT badge
+    pub fn claim_with_nfc(
+        e: &Env,
+        event_id: u32,
+        to: Address,
+        tag_key: BytesN<32>,
+        nonce_ledger: u32,
+        signature: BytesN<64>,
+    ) -> Result<u32, SpotError> {
+        to.require_auth();
+
+        let event_data = Self::get_event(e, event_id)?;
+        if !storage::has(e, &DataKey::NfcTag(event_id, tag_key.clone())) {
+            return Err(SpotError::InvalidNfcTag);
+        }
+
+        let current = e.ledger().sequence();
+        if nonce_ledger > current || current - nonce_ledger > NFC_NONCE_WINDOW_LEDGERS {
+            return Err(SpotError::NfcChallengeExpired);
+        }
+
+        // Panics (and aborts the claim) if the tag signature does not match
+        let payload = Self::nfc_payload(e, event_id, to.clone(), nonce_ledger);
+        e.crypto().ed25519_verify(&tag_key, &payload, &signature);
+
+        Self::mint_claim(e, &event_data, to)
+    }
+
+    /// Set or clear the geofence of an event (event creator, event manager or admin)
+    pub fn set_geofence(
+        e: &Env,
+        operator: Address,
+        event_id: u32,
+        geofence: Option<Geofence>,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_event_manager(e, &event_data, &operator)?;
+
+        match geofence {
+            Some(geofence) => {
+                if geofence.latitude.unsigned_abs() > geo::MAX_LATITUDE as u32
+                    || geofence.longitude.unsigned_abs() > geo::MAX_LONGITUDE as u32
+                    || geofence.radius_m == 0
+                {
+                    return Err(SpotError::InvalidParameters);
+                }
+                storage::write(e, &DataKey::Geofence(event_id), &geofence);
+            }
+            None => storage::remove(e, &DataKey::Geofence(event_id)),
+        }
+
+        EventUpdated { event_id, operator }.publish(e);
+        Ok(())
+    }
+
+    /// Get the geofence of an event
+    pub fn get_geofence(e: &Env, event_id: u32) -> Option<Geofence> {
+        storage::read(e, &DataKey::Geofence(event_id))
+    }
+
+    /// Get the exact payload an attestor must sign for a location attestation
+    /// (XDR of the tuple `(contract_address, attestation)`)
+    pub fn attestation_payload(e: &Env, attestation: LocationAttestation) -> Bytes {
+        (e.current_contract_address(), attestation).to_xdr(e)
+    }
+
+    /// Claim a SPOT badge for a geofenced event with a signed location attestation
+    /// 
+    /// # Arguments
+    /// * `event_id` - ID of the event
+    /// * `to` - Address that will receive the SPOT NFT (must authorize)
+    /// * `attestation` - Location of `to`, signed by the event's attestor
+    /// * `signature` - Attestor signature over `attestation_payload(attestation)`
+    /// 
+    /// # Returns
+    /// The token ID of the minted SPOT badge
+    pub fn claim_with_location(
+        e: &Env,
+        event_id: u32,
+        to: Address,
+        attestation: LocationAttestation,
+        signature: BytesN<64>,
+    ) -> Result<u32, SpotError> {
+        to.require_auth();
+
+        let event_data = Self::get_event(e, event_id)?;
+        let geofence: Geofence = storage::read(e, &DataKey::Geofence(event_id))
+            .ok_or(SpotError::InvalidAttestation)?;
+
+        let now = e.ledger().timestamp();
+        if attestation.event_id != event_id
+            || attestation.claimer != to
+            || attestation.timestamp > now
+            || now - attestation.timestamp > MAX_ATTESTATION_AGE
+        {
+            return Err(SpotError::InvalidAttestation);
+        }
+
+        // Panics (and aborts the claim) if the attestor signature does not match
+        let payload = Self::attestation_payload(e, attestation.clone());
+        e.crypto().ed25519_verify(&geofence.attestor, &payload, &signature);
+
+        if !geo::within_radius(
+            geofence.latitude,
+            geofence.longitude,
+            attestation.latitude,
+            attestation.longitude,
+            geofence.radius_m,
+        ) {
+            return Err(SpotError::OutsideGeofence);
+        }
+
+        let token_id = Self::mint_claim(e, &event_data, to.clone())?;
+
+        LocationVerified {
+            event_id,
+            to,
+            latitude: attestation.latitude,
+            longitude: attestation.longitude,
+            attested_at: attestation.timestamp,
+        }
+        .publish(e);
+
+        Ok(token_id)
+    }
+
+    /// Extend the TTL of the contract instance and of every event-level record: the
+    /// event, its counters, claim method settings, plan, pause, pending owner, change
+    /// history, collections and delegate roles. Per-claimer and per-secret records
+    /// (claims, tokens, voucher nonces, links and NFC tags) are extended when they are
+    /// next read. Permissionless: anyone can keep a popular event alive.
+    pub fn bump_event(e: &Env, event_id: u32) -> Result<(), SpotError> {
+        if !storage::has(e, &DataKey::EventInfo(event_id)) {
+            return Err(SpotError::EventNotFound);
+        }
+        let keys = [
+            DataKey::EventMintedCount(event_id),
+            DataKey::VoucherIssuer(event_id),
+            DataKey::ClaimCode(event_id),
+            DataKey::LinksRemaining(event_id),
+            DataKey::NfcTagCount(event_id),
+            DataKey::Geofence(event_id),
+            DataKey::EventPlan(event_id),
+            DataKey::EventCredits(event_id),
+            DataKey::EventPause(event_id),
+            DataKey::PendingEventOwner(event_id),
+            DataKey::EventChanges(event_id),
+        ];
+        for key in keys.iter() {
+            storage::has(e, key);
+        }
+
+        let collection_count: u32 = storage::read(e, &DataKey::CollectionCount(event_id)).unwrap_or(0);
+        for collection_id in 1..=collection_count {
+            storage::has(e, &DataKey::Collection(event_id, collection_id));
+        }
+        for account in Self::delegate_addresses(e, event_id).iter() {
+            storage::has(e, &DataKey::EventRole(event_id, account));
+        }
+        storage::extend_instance(e);
+        Ok(())
+    }
+
+    /// Check if an address has claimed a SPOT badge for a specific event
+    pub fn has_claimed(e: &Env, event_id: u32, address: Address) -> bool {
+        Self::has_claim_record(e, event_id, &address)
+    }
+
+    /// Get event information
+    pub fn get_event(e: &Env, event_id: u32) -> Result<EventData, SpotError> {
+        storage::read(e, &DataKey::EventInfo(event_id))
+            .ok_or(SpotError::EventNotFound)
+    }
+
+    /// Get the number of SPOT badges minted for an event
+    pub fn minted_count(e: &Env, event_id: u32) -> Result<u32, SpotError> {
+        // Verify event exists
+        let _event_data: EventData = storage::read(e, &DataKey::EventInfo(event_id))
+            .ok_or(SpotError::EventNotFound)?;
+        
+        Ok(storage::read(e, &DataKey::EventMintedCount(event_id))
+            .unwrap_or(0u32))
+    }
+
+    /// List event IDs in creation order, optionally filtered by status
+    ///
+    /// # Arguments
+    /// * `status` - Only return events in this state (None = all)
+    /// * `start` - Index of the first event ID to scan (0-based)
+    /// * `limit` - Number of event IDs to scan (capped at 50)
+    ///
+    /// The status filter applies within the scanned page, so a page can hold fewer than
+    /// `limit` IDs; keep paging until `start` reaches `event_count()`.
+    pub fn get_all_events(e: &Env, status: Option<EventStatus>, start: u32, limit: u32) -> Vec<u32> {
+        let event_counter = Self::event_count(e);
+        let end = event_counter.min(start.saturating_add(limit.min(MAX_PAGE_SIZE)));
+
+        let mut events = Vec::new(e);
+        for index in start..end {
+            let i = index + 1;
+            match status {
+                None => {
+                    if e.storage().persistent().has(&DataKey::EventInfo(i)) {
+                        events.push_back(i);
+                    }
+                }
+                Some(status) => {
+                    let event_data: Option<EventData> =
+                        e.storage().persistent().get(&DataKey::EventInfo(i));
+                    if event_data.is_some_and(|event_data| event_data.status == status) {
+                        events.push_back(i);
+                    }
+                }
+            }
+        }
+        events
+    }
+
+    /// Get the total number of events
+    pub fn event_count(e: &Env) -> u32 {
+        e.storage().instance().get(&DataKey::EventCounter)
+            .unwrap_or(0u32)
+    }
+
+    /// Get the token ID for a specific event and token index
+    /// 
+    /// # Arguments
+    /// * `event_id` - ID of the event
+    /// * `token_index` - Index of the token (0-based, corresponds to minting order)
+    /// 
+    /// # Returns
+    /// The token ID if it exists
+    pub fn get_token_id_for_event(e: &Env, event_id: u32, token_index: u32) -> Result<u32, SpotError> {
+        // Verify event exists
+        let _event_data: EventData = storage::read(e, &DataKey::EventInfo(event_id))
+            .ok_or(SpotError::EventNotFound)?;
+        
+        storage::read(e, &DataKey::EventTokenId(event_id, token_index))
+            .ok_or(SpotError::EventNotFound)
+    }
+
+    /// Get all token IDs minted for a specific event
+    /// 
+    /// # Arguments
+    /// * `event_id` - ID of the event
+    /// 
+    /// # Returns
+    /// A vector of all token IDs minted for the event
+    pub fn get_event_poaps(e: &Env, event_id: u32) -> Result<Vec<u32>, SpotError> {
+        // Verify event exists
+        let _event_data: EventData = storage::read(e, &DataKey::EventInfo(event_id))
+            .ok_or(SpotError::EventNotFound)?;
+        
+        let minted: u32 = storage::read(e, &DataKey::EventMintedCount(event_id))
+            .unwrap_or(0u32);
+        
+        let mut token_ids = Vec::new(e);
+        for i in 0..minted {
+            if let Some(token_id) = storage::read(e, &DataKey::EventTokenId(event_id, i)) {
+                token_ids.push_back(token_id);
+            }
+        }
+        
+        Ok(token_ids)
+    }
+
+    /// Get the token ID of a SPOT badge claimed by a specific address for an event
+    /// 
+    /// # Arguments
+    /// * `event_id` - ID of the event
+    /// * `address` - Address that claimed the SPOT badge
+    /// 
+    /// # Returns
+    /// The token ID if the address has claimed a SPOT badge for this event
+    pub fn get_user_poap_for_event(e: &Env, event_id: u32, address: Address) -> Result<u32, SpotError> {
+        // Verify event exists
+        let _event_data: EventData = storage::read(e, &DataKey::EventInfo(event_id))
+            .ok_or(SpotError::EventNotFound)?;
+        
+        // Check if address has claimed
+        if !Self::has_claim_record(e, event_id, &address) {
+            return Err(SpotError::EventNotFound); // Address hasn't claimed
+        }
+        
+        // Get token ID directly from the mapping (schema 1 claims stay in instance storage)
+        storage::read(e, &DataKey::UserEventTokenId(event_id, address.clone()))
+            .or_else(|| migration::legacy_user_token(e, event_id, &address))
+            .ok_or(SpotError::EventNotFound)
+    }
+
+    /// Schedule granting the admin role to an address
+    /// Only the contract admin can schedule it; `operator` must be that admin and authorize.
+    /// The role is granted through `execute_operation` once the timelock delay has passed.
+    ///
+    /// # Returns
+    /// The ID of the scheduled operation
+    pub fn grant_admin_role(e: &Env, admin: Address, operator: Address) -> Result<u64, SpotError> {
+        operator.require_auth();
+        Self::require_contract_admin(e, &operator)?;
+
+        let operation = timelock::schedule(e, TimelockAction::GrantAdmin(admin))?;
+        Ok(operation.id)
+    }
+
+    /// Update event information (event creator, event manager or admin).
+    /// Capacity and claim window change through `set_event_capacity` and `set_claim_window`.
+    pub fn update_event(
+        e: &Env,
+        operator: Address,
+        event_id: u32,
+        event_name: Option<String>,
+        event_date: Option<u64>,
+        location: Option<String>,
+        description: Option<String>,
+        metadata_uri: Option<String>,
+        image_url: Option<String>,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+
+        let mut event_data: EventData = storage::read(e, &DataKey::EventInfo(event_id))
+            .ok_or(SpotError::EventNotFound)?;
+
+        // Verify operator is event creator, event manager or admin
+        Self::require_event_manager(e, &event_data, &operator)?;
+
+        // Published events keep their identity; cancelled and closed events are final
+        match event_data.status {
+            EventStatus::Draft => {}
+            EventStatus::Published => {
+                if event_name.is_some() || event_date.is_some() || metadata_uri.is_some() {
+                    return Err(SpotError::InvalidEventStatus);
+                }
+            }
+            EventStatus::Cancelled | EventStatus::Closed => {
+                return Err(SpotError::InvalidEventStatus);
+            }
+        }
+
+        // Update fields if provided
+        if let Some(name) = event_name {
+            event_data.event_name = name;
+        }
+        if let Some(date) = event_date {
+            event_data.event_date = date;
+        }
+        if let Some(loc) = location {
+            event_data.location = loc;
+        }
+        if let Some(desc) = description {
+            event_data.description = desc;
+        }
+        if let Some(uri) = metadata_uri {
+            event_data.metadata_uri = uri;
+        }
+        if let Some(img) = image_url {
+            event_data.image_url = img;
+        }
+
+        // Save updated event data
+        storage::write(e, &DataKey::EventInfo(event_id), &event_data);
+
+        EventUpdated { event_id, operator }.publish(e);
+
+        Ok(())
+    }
+
+    /// Change the capacity of a draft or published event (event creator, event manager or
+    /// admin). Capacity can rise within the creator's plan, charging credits on paid plans,
+    /// and can fall down to the badges already minted, refunding credits.
+    pub fn set_event_capacity(
+        e: &Env,
+        operator: Address,
+        event_id: u32,
+        max_poaps: u32,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+        let mut event_data = Self::get_event(e, event_id)?;
+        Self::require_event_manager(e, &event_data, &operator)?;
+
+        if !matches!(event_data.status, EventStatus::Draft | EventStatus::Published) {
+            return Err(SpotError::InvalidEventStatus);
+        }
+        let minted = Self::minted_count(e, event_id)?;
+        if max_poaps == 0 || max_poaps < minted || max_poaps == event_data.max_poaps {
+            return Err(SpotError::InvalidParameters);
+        }
+
+        if let Some((payer, plan)) = Self::event_plan(e, event_id) {
+            if max_poaps > event_data.max_poaps {
+                let claim_window = event_data.claim_end - event_data.claim_start;
+                let capacity = max_poaps - event_data.max_poaps;
+                Self::charge_plan(e, &payer, plan, event_id, capacity, claim_window)?;
+            } else {
+                let capacity = event_data.max_poaps - max_poaps;
+                Self::release_plan_capacity(e, &payer, plan, event_id, capacity);
+            }
+        }
+
+        let (old_value, new_value) = (event_data.max_poaps as u64, max_poaps as u64);
+        Self::record_change(e, event_id, EventField::MaxPoaps, old_value, new_value, &operator);
+        event_data.max_poaps = max_poaps;
+        storage::write(e, &DataKey::EventInfo(event_id), &event_data);
+        Ok(())
+    }
+
+    /// Change the claim window of a draft or published event (event creator, event manager
+    /// or admin). Drafts can be rescheduled freely. Published events can only extend their
+    /// window: `claim_start` can move earlier while claiming has not started, never into the
+    /// past, and `claim_end` can only move later. An ended window can be reopened until
+    /// `REOPEN_GRACE_PERIOD` after its `claim_end`, as a new window that starts at or after
+    /// the old `claim_start` and ends in the future. The free plan's limit applies to the
+    /// length of the new window.
+    pub fn set_claim_window(
+        e: &Env,
+        operator: Address,
+        event_id: u32,
+        claim_start: u64,
+        claim_end: u64,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+        let mut event_data = Self::get_event(e, event_id)?;
+        Self::require_event_manager(e, &event_data, &operator)?;
+
+        let (old_start, old_end) = (event_data.claim_start, event_data.claim_end);
+        if claim_end < claim_start || (claim_start == old_start && claim_end == old_end) {
+            return Err(SpotError::InvalidParameters);
+        }
+        let now = e.ledger().timestamp();
+        match event_data.status {
+            EventStatus::Draft => {}
+            EventStatus::Published if now > old_end => {
+                // Reopening: nobody can claim in the ended window, so the new one may start later
+                if now - old_end > REOPEN_GRACE_PERIOD {
+                    return Err(SpotError::ClaimPeriodEnded);
+                }
+                if claim_start < old_start || claim_end < now {
+                    return Err(SpotError::InvalidParameters);
+                }
+            }
+            EventStatus::Published => {
+                if claim_start > old_start
+                    || (claim_start < old_start && (now >= old_start || claim_start < now))
+                {
+                    return Err(SpotError::InvalidParameters);
+                }
+                if claim_end < old_end {
+                    return Err(SpotError::InvalidParameters);
+                }
+            }
+            EventStatus::Cancelled | EventStatus::Closed => {
+                return Err(SpotError::InvalidEventStatus);
+            }
+        }
+
+        if let Some((payer, plan)) = Self::event_plan(e, event_id) {
+            Self::charge_plan(e, &payer, plan, event_id, 0, claim_end - claim_start)?;
+        }
+
+        if claim_start != old_start {
+            Self::record_change(e, event_id, EventField::ClaimStart, old_start, claim_start, &operator);
+        }
+        if claim_end != old_end {
+            Self::record_change(e, event_id, EventField::ClaimEnd, old_end, claim_end, &operator);
+        }
+        event_data.claim_start = claim_start;
+        event_data.claim_end = claim_end;
+        storage::write(e, &DataKey::EventInfo(event_id), &event_data);
+        Ok(())
+    }
+
+    /// Change history of an event's capacity and claim window, oldest first
+    pub fn get_event_changes(e: &Env, event_id: u32) -> Vec<EventChange> {
+        storage::read(e, &DataKey::EventChanges(event_id)).unwrap_or(Vec::new(e))
+    }
+
+    /// Publish a draft event, making it claimable (event creator, event manager or admin)
+    pub fn publish_event(e: &Env, operator: Address, event_id: u32) -> Result<(), SpotError> {
+        operator.require_auth();
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_event_manager(e, &event_data, &operator)?;
+
+        if event_data.status != EventStatus::Draft {
+            return Err(SpotError::InvalidEventStatus);
+        }
+        Self::set_event_status(e, event_data, EventStatus::Published, operator);
+        Ok(())
+    }
 
-        // Update counters and tracking
-        e.storage().instance().set(&DataKey::EventMintedCount(event_id), &(minted + 1));
-        e.storage().instance().set(&DataKey::HasClaimed(event_id, to.clone()), &true);
-        e.storage().instance().set(&DataKey::EventTokenId(event_id, minted), &token_id);
-        e.storage().instance().set(&DataKey::UserEventTokenId(event_id, to.clone()), &token_id);
+    /// Cancel a draft or published event (event owner, co-organizer or admin).
+    /// Claims are blocked and badges already minted are reported as cancelled.
+    pub fn cancel_event(e: &Env, operator: Address, event_id: u32) -> Result<(), SpotError> {
+        operator.require_auth();
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_event_organizer(e, &event_data, &operator)?;
+
+        if !matches!(event_data.status, EventStatus::Draft | EventStatus::Published) {
+            return Err(SpotError::InvalidEventStatus);
+        }
+        Self::set_event_status(e, event_data, EventStatus::Cancelled, operator);
+        Ok(())
+    }
+
+    /// Close a published event, freezing its supply (event creator, event manager or admin)
+    pub fn close_event(e: &Env, operator: Address, event_id: u32) -> Result<(), SpotError> {
+        operator.require_auth();
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_event_manager(e, &event_data, &operator)?;
+
+        if event_data.status != EventStatus::Published {
+            return Err(SpotError::InvalidEventStatus);
+        }
+        Self::set_event_status(e, event_data, EventStatus::Closed, operator);
+        Ok(())
+    }
+
+    /// Add a badge collection to an event (event creator, event manager or admin)
+    ///
+    /// # Arguments
+    /// * `operator` - Event creator, event manager or admin
+    /// * `event_id` - ID of the event (draft or published)
+    /// * `name` - Collection name, e.g. "Speaker"
+    /// * `max_supply` - Public cap for `claim_collection`
+    /// * `reserved_supply` - Badges only organizers can mint via `mint_reserved`
+    /// * `claim_start` / `claim_end` - Claim window of the collection
+    /// * `metadata_uri` / `image_url` - Collection metadata and image
+    ///
+    /// # Returns
+    /// The collection ID (starting at 1)
+    pub fn add_collection(
+        e: &Env,
+        operator: Address,
+        event_id: u32,
+        name: String,
+        max_supply: u32,
+        reserved_supply: u32,
+        claim_start: u64,
+        claim_end: u64,
+        metadata_uri: String,
+        image_url: String,
+    ) -> Result<u32, SpotError> {
+        operator.require_auth();
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_event_manager(e, &event_data, &operator)?;
+
+        if !matches!(event_data.status, EventStatus::Draft | EventStatus::Published) {
+            return Err(SpotError::InvalidEventStatus);
+        }
+        if (max_supply == 0 && reserved_supply == 0) || claim_end < claim_start {
+            return Err(SpotError::InvalidParameters);
+        }
+        let collection_id = Self::collection_count(e, event_id) + 1;
+        if collection_id > MAX_COLLECTIONS {
+            return Err(SpotError::InvalidParameters);
+        }
+        if let Some((payer, plan)) = Self::event_plan(e, event_id) {
+            let capacity = max_supply
+                .checked_add(reserved_supply)
+                .ok_or(SpotError::InvalidParameters)?;
+            let claim_window = claim_end - claim_start;
+            Self::charge_plan(e, &payer, plan, event_id, capacity, claim_window)?;
+        }
+
+        let collection = Collection {
+            collection_id,
+            name: name.clone(),
+            max_supply,
+            reserved_supply,
+            claim_start,
+            claim_end,
+            metadata_uri,
+            image_url,
+            minted: 0,
+            reserved_minted: 0,
+        };
+        storage::write(e, &DataKey::Collection(event_id, collection_id), &collection);
+        storage::write(e, &DataKey::CollectionCount(event_id), &collection_id);
+
+        CollectionAdded {
+            event_id,
+            collection_id,
+            name,
+            max_supply,
+            reserved_supply,
+        }
+        .publish(e);
+
+        Ok(collection_id)
+    }
+
+    /// Get a collection of an event
+    pub fn get_collection(e: &Env, event_id: u32, collection_id: u32) -> Result<Collection, SpotError> {
+        storage::read(e, &DataKey::Collection(event_id, collection_id))
+            .ok_or(SpotError::CollectionNotFound)
+    }
+
+    /// Number of collections of an event
+    pub fn collection_count(e: &Env, event_id: u32) -> u32 {
+        storage::read(e, &DataKey::CollectionCount(event_id)).unwrap_or(0u32)
+    }
+
+    /// Claim a badge from a collection's public supply
+    ///
+    /// # Arguments
+    /// * `event_id` - ID of the event
+    /// * `collection_id` - ID of the collection
+    /// * `to` - Address that will receive the badge (must authorize)
+    pub fn claim_collection(
+        e: &Env,
+        event_id: u32,
+        collection_id: u32,
+        to: Address,
+    ) -> Result<u32, SpotError> {
+        to.require_auth();
+
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_open_claim(e, &event_data)?;
+
+        let mut collection = Self::get_collection(e, event_id, collection_id)?;
+        let current_time = e.ledger().timestamp();
+        if current_time < collection.claim_start {
+            return Err(SpotError::ClaimPeriodNotStarted);
+        }
+        if current_time > collection.claim_end {
+            return Err(SpotError::ClaimPeriodEnded);
+        }
+        if collection.minted >= collection.max_supply {
+            return Err(SpotError::LimitExceeded);
+        }
+
+        let token_id = Self::mint_collection_badge(e, &event_data, collection_id, to)?;
+        collection.minted += 1;
+        storage::write(e, &DataKey::Collection(event_id, collection_id), &collection);
+
+        Ok(token_id)
+    }
+
+    /// Mint a badge from a collection's reserved supply (event creator, admin or event
+    /// minter). The collection's claim window does not apply.
+    pub fn mint_reserved(
+        e: &Env,
+        operator: Address,
+        event_id: u32,
+        collection_id: u32,
+        to: Address,
+    ) -> Result<u32, SpotError> {
+        operator.require_auth();
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_event_minter(e, &event_data, &operator)?;
+
+        let mut collection = Self::get_collection(e, event_id, collection_id)?;
+        if collection.reserved_minted >= collection.reserved_supply {
+            return Err(SpotError::LimitExceeded);
+        }
+
+        let token_id = Self::mint_collection_badge(e, &event_data, collection_id, to)?;
+        collection.reserved_minted += 1;
+        storage::write(e, &DataKey::Collection(event_id, collection_id), &collection);
 
         Ok(token_id)
     }
 
-    /// Check if an address has claimed a SPOT badge for a specific event
-    pub fn has_claimed(e: &Env, event_id: u32, address: Address) -> bool {
-        e.storage().instance().has(&DataKey::HasClaimed(event_id, address))
+    /// Get the collection a badge belongs to (0 for the default event badge)
+    pub fn get_badge_collection(e: &Env, token_id: u32) -> Result<u32, SpotError> {
+        Self::get_badge_event(e, token_id)?;
+        Ok(storage::read(e, &DataKey::TokenCollection(token_id)).unwrap_or(0u32))
+    }
+
+    /// Whether an address holds a badge of a collection
+    pub fn has_claimed_collection(e: &Env, event_id: u32, collection_id: u32, address: Address) -> bool {
+        storage::has(e, &DataKey::CollectionClaimed(event_id, collection_id, address))
+    }
+
+    /// Get the event a badge was minted for
+    pub fn get_badge_event(e: &Env, token_id: u32) -> Result<u32, SpotError> {
+        storage::read(e, &DataKey::TokenEvent(token_id)).ok_or(SpotError::EventNotFound)
+    }
+
+    /// Whether a badge belongs to a cancelled event
+    pub fn is_badge_cancelled(e: &Env, token_id: u32) -> Result<bool, SpotError> {
+        let event_id = Self::get_badge_event(e, token_id)?;
+        let event_data = Self::get_event(e, event_id)?;
+        Ok(event_data.status == EventStatus::Cancelled)
+    }
+
+    /// Grant an event-scoped role to an account (event owner, co-organizer or admin).
+    /// Replaces the account's previous role for the event, if any. Only the event owner
+    /// or an admin can grant or replace the `Organizer` role.
+    pub fn grant_event_role(
+        e: &Env,
+        operator: Address,
+        event_id: u32,
+        account: Address,
+        role: EventRole,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_event_organizer(e, &event_data, &operator)?;
+        let current_role = Self::get_event_role(e, event_id, account.clone());
+        if role == EventRole::Organizer || current_role == Some(EventRole::Organizer) {
+            Self::require_event_owner_or_admin(e, &event_data, &operator)?;
+        }
+        if account == event_data.creator {
+            return Err(SpotError::InvalidParameters);
+        }
+
+        let role_key = DataKey::EventRole(event_id, account.clone());
+        if current_role.is_none() {
+            let mut delegates = Self::delegate_addresses(e, event_id);
+            if delegates.len() >= MAX_EVENT_DELEGATES {
+                return Err(SpotError::InvalidParameters);
+            }
+            delegates.push_back(account.clone());
+            storage::write(e, &DataKey::EventDelegates(event_id), &delegates);
+        }
+        storage::write(e, &role_key, &role);
+
+        EventRoleGranted {
+            event_id,
+            account,
+            role,
+            operator,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    /// Revoke an account's event-scoped role (event owner, co-organizer or admin; only the
+    /// event owner or an admin can revoke a co-organizer)
+    pub fn revoke_event_role(
+        e: &Env,
+        operator: Address,
+        event_id: u32,
+        account: Address,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_event_organizer(e, &event_data, &operator)?;
+        match Self::get_event_role(e, event_id, account.clone()) {
+            None => return Err(SpotError::InvalidParameters),
+            Some(EventRole::Organizer) => Self::require_event_owner_or_admin(e, &event_data, &operator)?,
+            Some(_) => {}
+        }
+        Self::remove_event_role(e, event_id, &account);
+
+        EventRoleRevoked {
+            event_id,
+            account,
+            operator,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    /// Get an account's event-scoped role, if any
+    pub fn get_event_role(e: &Env, event_id: u32, account: Address) -> Option<EventRole> {
+        storage::read(e, &DataKey::EventRole(event_id, account))
+    }
+
+    /// Propose a new owner for an event (only event owner or admin). The transfer completes
+    /// when `new_owner` calls `accept_event_owner` by `live_until_ledger`; a
+    /// `live_until_ledger` of 0 cancels the pending proposal.
+    ///
+    /// Co-organizers keep managing the event while a handover is pending or if it expires.
+    pub fn propose_event_owner(
+        e: &Env,
+        operator: Address,
+        event_id: u32,
+        new_owner: Address,
+        live_until_ledger: u32,
+    ) -> Result<(), SpotError> {
+        operator.require_auth();
+        let event_data = Self::get_event(e, event_id)?;
+        Self::require_event_owner_or_admin(e, &event_data, &operator)?;
+
+        let key = DataKey::PendingEventOwner(event_id);
+        if live_until_ledger == 0 {
+            let pending: PendingOwner = storage::read(e, &key).ok_or(SpotError::InvalidParameters)?;
+            if pending.account != new_owner {
+                return Err(SpotError::InvalidParameters);
+            }
+            storage::remove(e, &key);
+        } else {
+            if new_owner == event_data.creator || live_until_ledger < e.ledger().sequence() {
+                return Err(SpotError::InvalidParameters);
+            }
+            let pending = PendingOwner {
+                account: new_owner.clone(),
+                live_until_ledger,
+            };
+            storage::write(e, &key, &pending);
+        }
+
+        EventOwnerProposed {
+            event_id,
+            new_owner,
+            live_until_ledger,
+            operator,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    /// Accept the ownership of an event proposed with `propose_event_owner`.
+    /// Any event-scoped role the new owner held is dropped.
+    pub fn accept_event_owner(e: &Env, new_owner: Address, event_id: u32) -> Result<(), SpotError> {
+        new_owner.require_auth();
+        let mut event_data = Self::get_event(e, event_id)?;
+
+        let key = DataKey::PendingEventOwner(event_id);
+        let pending: PendingOwner = storage::read(e, &key).ok_or(SpotError::Unauthorized)?;
+        if pending.account != new_owner {
+            return Err(SpotError::Unauthorized);
+        }
+        if e.ledger().sequence() > pending.live_until_ledger {
+            return Err(SpotError::TransferExpired);
+        }
+
+        storage::remove(e, &key);
+        if Self::get_event_role(e, event_id, new_owner.clone()).is_some() {
+            Self::remove_event_role(e, event_id, &new_owner);
+        }
+        let previous_owner = event_data.creator;
+        event_data.creator = new_owner.clone();
+        storage::write(e, &DataKey::EventInfo(event_id), &event_data);
+
+        EventOwnerChanged {
+            event_id,
+            previous_owner,
+            new_owner,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    /// Pending owner proposal of an event, if any
+    pub fn get_pending_event_owner(e: &Env, event_id: u32) -> Option<PendingOwner> {
+        storage::read(e, &DataKey::PendingEventOwner(event_id))
     }
 
-    /// Get event information
-    pub fn get_event(e: &Env, event_id: u32) -> Result<EventData, SpotError> {
-        e.storage().instance().get(&DataKey::EventInfo(event_id))
-            .ok_or(SpotError::EventNotFound)
+    /// List the delegates of an event with their roles
+    pub fn get_event_delegates(e: &Env, event_id: u32) -> Vec<EventDelegate> {
+        let mut delegates = Vec::new(e);
+        for account in Self::delegate_addresses(e, event_id).iter() {
+            if let Some(role) = storage::read(e, &DataKey::EventRole(event_id, account.clone())) {
+                delegates.push_back(EventDelegate { account, role });
+            }
+        }
+        delegates
     }
 
-    /// Get the number of SPOT badges minted for an event
-    pub fn minted_count(e: &Env, event_id: u32) -> Result<u32, SpotError> {
-        // Verify event exists
-        let _event_data: EventData = e.storage().instance().get(&DataKey::EventInfo(event_id))
-            .ok_or(SpotError::EventNotFound)?;
-        
-        Ok(e.storage().instance().get(&DataKey::EventMintedCount(event_id))
-            .unwrap_or(0u32))
+    // Helper functions for role checking
+    /// Event owner, co-organizer or admin
+    fn require_event_organizer(
+        e: &Env,
+        event_data: &EventData,
+        address: &Address,
+    ) -> Result<(), SpotError> {
+        let role = Self::get_event_role(e, event_data.event_id, address.clone());
+        if role == Some(EventRole::Organizer) {
+            return Ok(());
+        }
+
+        Self::require_event_owner_or_admin(e, event_data, address)
     }
 
-    /// Get all event IDs (returns a vector of event IDs)
-    pub fn get_all_events(e: &Env) -> Vec<u32> {
-        let event_counter: u32 = e.storage().instance().get(&DataKey::EventCounter)
-            .unwrap_or(0u32);
-        
-        let mut events = Vec::new(e);
-        for i in 1..=event_counter {
-            if e.storage().instance().has(&DataKey::EventInfo(i)) {
-                events.push_back(i);
-            }
+    fn require_event_owner_or_admin(
+        e: &Env,
+        event_data: &EventData,
+        address: &Address,
+    ) -> Result<(), SpotError> {
+        if event_data.creator == *address {
+            return Ok(());
         }
-        events
+
+        Self::require_admin(e, address)
     }
 
-    /// Get the total number of events
-    pub fn event_count(e: &Env) -> u32 {
-        e.storage().instance().get(&DataKey::EventCounter)
-            .unwrap_or(0u32)
+    /// Event creator, admin or an event manager
+    fn require_event_manager(
+        e: &Env,
+        event_data: &EventData,
+        address: &Address,
+    ) -> Result<(), SpotError> {
+        let role = Self::get_event_role(e, event_data.event_id, address.clone());
+        if role == Some(EventRole::Manager) {
+            return Ok(());
+        }
+
+        Self::require_event_organizer(e, event_data, address)
     }
 
-    /// Get the token ID for a specific event and token index
-    /// 
-    /// # Arguments
-    /// * `event_id` - ID of the event
-    /// * `token_index` - Index of the token (0-based, corresponds to minting order)
-    /// 
-    /// # Returns
-    /// The token ID if it exists
-    pub fn get_token_id_for_event(e: &Env, event_id: u32, token_index: u32) -> Result<u32, SpotError> {
-        // Verify event exists
-        let _event_data: EventData = e.storage().instance().get(&DataKey::EventInfo(event_id))
-            .ok_or(SpotError::EventNotFound)?;
-        
-        e.storage().instance().get(&DataKey::EventTokenId(event_id, token_index))
-            .ok_or(SpotError::EventNotFound)
+    /// Event creator, admin or an event minter/manager
+    fn require_event_minter(
+        e: &Env,
+        event_data: &EventData,
+        address: &Address,
+    ) -> Result<(), SpotError> {
+        let role = Self::get_event_role(e, event_data.event_id, address.clone());
+        if matches!(role, Some(EventRole::Minter) | Some(EventRole::Manager)) {
+            return Ok(());
+        }
+
+        Self::require_event_organizer(e, event_data, address)
     }
 
-    /// Get all token IDs minted for a specific event
-    /// 
-    /// # Arguments
-    /// * `event_id` - ID of the event
-    /// 
-    /// # Returns
-    /// A vector of all token IDs minted for the event
-    pub fn get_event_poaps(e: &Env, event_id: u32) -> Result<Vec<u32>, SpotError> {
-        // Verify event exists
-        let _event_data: EventData = e.storage().instance().get(&DataKey::EventInfo(event_id))
-            .ok_or(SpotError::EventNotFound)?;
-        
-        let minted: u32 = e.storage().instance().get(&DataKey::EventMintedCount(event_id))
-            .unwrap_or(0u32);
-        
-        let mut token_ids = Vec::new(e);
-        for i in 0..minted {
-            if let Some(token_id) = e.storage().instance().get(&DataKey::EventTokenId(event_id, i)) {
-                token_ids.push_back(token_id);
-            }
+    /// Check the event status and duplicate rule, then mint a collection badge for `to`.
+    /// Callers check and update the collection supply themselves.
+    fn mint_collection_badge(
+        e: &Env,
+        event_data: &EventData,
+        collection_id: u32,
+        to: Address,
+    ) -> Result<u32, SpotError> {
+        let event_id = event_data.event_id;
+        if event_data.status != EventStatus::Published {
+            return Err(SpotError::InvalidEventStatus);
         }
-        
-        Ok(token_ids)
+        Self::require_event_not_paused(e, event_id)?;
+
+        let claimed_key = DataKey::CollectionClaimed(event_id, collection_id, to.clone());
+        if storage::has(e, &claimed_key) {
+            return Err(SpotError::AlreadyClaimed);
+        }
+
+        let token_id = Enumerable::sequential_mint(e, &to);
+        storage::write(e, &claimed_key, &true);
+        storage::write(e, &DataKey::TokenEvent(token_id), &event_id);
+        storage::write(e, &DataKey::TokenCollection(token_id), &collection_id);
+        storage::extend_instance(e);
+
+        CollectionBadgeMinted {
+            event_id,
+            collection_id,
+            to,
+            token_id,
+        }
+        .publish(e);
+
+        Ok(token_id)
     }
 
-    /// Get the token ID of a SPOT badge claimed by a specific address for an event
-    /// 
-    /// # Arguments
-    /// * `event_id` - ID of the event
-    /// * `address` - Address that claimed the SPOT badge
-    /// 
-    /// # Returns
-    /// The token ID if the address has claimed a SPOT badge for this event
-    pub fn get_user_poap_for_event(e: &Env, event_id: u32, address: Address) -> Result<u32, SpotError> {
-        // Verify event exists
-        let _event_data: EventData = e.storage().instance().get(&DataKey::EventInfo(event_id))
-            .ok_or(SpotError::EventNotFound)?;
-        
-        // Check if address has claimed
-        if !e.storage().instance().has(&DataKey::HasClaimed(event_id, address.clone())) {
-            return Err(SpotError::EventNotFound); // Address hasn't claimed
+    fn set_event_status(e: &Env, mut event_data: EventData, status: EventStatus, operator: Address) {
+        event_data.status = status;
+        storage::write(e, &DataKey::EventInfo(event_data.event_id), &event_data);
+
+        if matches!(status, EventStatus::Cancelled | EventStatus::Closed) {
+            Self::refund_unused_credits(e, &event_data);
         }
-        
-        // Get token ID directly from the mapping
-        e.storage().instance().get(&DataKey::UserEventTokenId(event_id, address))
-            .ok_or(SpotError::EventNotFound)
+
+        EventStatusChanged {
+            event_id: event_data.event_id,
+            status,
+            operator,
+        }
+        .publish(e);
     }
 
-    /// Grant admin role to an address
-    pub fn grant_admin_role(e: &Env, admin: Address, operator: Address) -> Result<(), SpotError> {
-        let contract_admin = Self::admin(e)?;
-        contract_admin.require_auth();
-        access_control::grant_role(e, &operator, &admin, &symbol_short!("admin"));
-        Ok(())
+    /// Plan of an approved creator
+    fn creator_plan(e: &Env, creator: &Address) -> Result<Plan, SpotError> {
+        let approval: CreatorApproval = storage::read(e, &DataKey::CreatorApproval(creator.clone()))
+            .ok_or(SpotError::CreatorNotApproved)?;
+        Ok(approval.plan)
     }
 
-    /// Update event information (only event creator or admin can update)
-    pub fn update_event(
+    /// Account charged for an event and the plan it was created under. The payer stays
+    /// the original creator when the event changes owner.
+    fn event_plan(e: &Env, event_id: u32) -> Option<(Address, Plan)> {
+        storage::read(e, &DataKey::EventPlan(event_id))
+    }
+
+    /// Enforce plan limits for `capacity` new badges of an event and, on paid plans,
+    /// consume the creator's credits for them
+    fn charge_plan(
         e: &Env,
-        operator: Address,
+        creator: &Address,
+        plan: Plan,
         event_id: u32,
-        event_name: Option<String>,
-        event_date: Option<u64>,
-        location: Option<String>,
-        description: Option<String>,
-        metadata_uri: Option<String>,
-        image_url: Option<String>,
+        capacity: u32,
+        claim_window: u64,
     ) -> Result<(), SpotError> {
-        let mut event_data: EventData = e.storage().instance().get(&DataKey::EventInfo(event_id))
-            .ok_or(SpotError::EventNotFound)?;
+        let charged: u32 = storage::read(e, &DataKey::EventCredits(event_id)).unwrap_or(0u32);
+        let total = charged.checked_add(capacity).ok_or(SpotError::InvalidParameters)?;
 
-        // Verify operator is event creator or admin
-        if event_data.creator != operator {
-            Self::require_admin(e, &operator)?;
+        if plan == Plan::Free {
+            if total > FREE_MAX_POAPS || claim_window > FREE_MAX_CLAIM_WINDOW {
+                return Err(SpotError::PlanLimitExceeded);
+            }
+        } else {
+            let credits = Self::get_credits(e, creator.clone());
+            if credits < capacity {
+                return Err(SpotError::InsufficientCredits);
+            }
+            storage::write(e, &DataKey::CreatorCredits(creator.clone()), &(credits - capacity));
         }
 
-        // Update fields if provided
-        if let Some(name) = event_name {
-            event_data.event_name = name;
+        // Free events also track their capacity so the per-event cap covers collections
+        storage::write(e, &DataKey::EventCredits(event_id), &total);
+        Ok(())
+    }
+
+    /// Return the credits for capacity that was never minted to the account that paid
+    /// for a paid-plan event
+    fn refund_unused_credits(e: &Env, event_data: &EventData) {
+        let event_id = event_data.event_id;
+        let payer = match Self::event_plan(e, event_id) {
+            Some((payer, plan)) if plan != Plan::Free => payer,
+            _ => return,
+        };
+        let charged: u32 = storage::read(e, &DataKey::EventCredits(event_id)).unwrap_or(0u32);
+
+        let mut used: u32 = storage::read(e, &DataKey::EventMintedCount(event_id)).unwrap_or(0u32);
+        for collection_id in 1..=Self::collection_count(e, event_id) {
+            let collection: Option<Collection> =
+                storage::read(e, &DataKey::Collection(event_id, collection_id));
+            if let Some(collection) = collection {
+                used += collection.minted + collection.reserved_minted;
+            }
         }
-        if let Some(date) = event_date {
-            event_data.event_date = date;
+
+        let refund = charged.saturating_sub(used);
+        if refund > 0 {
+            Self::add_credits(e, &payer, refund);
         }
-        if let Some(loc) = location {
-            event_data.location = loc;
+        storage::write(e, &DataKey::EventCredits(event_id), &used.min(charged));
+    }
+
+    /// Give back capacity charged by `charge_plan`, refunding credits on paid plans
+    fn release_plan_capacity(e: &Env, creator: &Address, plan: Plan, event_id: u32, capacity: u32) {
+        let charged: u32 = storage::read(e, &DataKey::EventCredits(event_id)).unwrap_or(0u32);
+        storage::write(e, &DataKey::EventCredits(event_id), &charged.saturating_sub(capacity));
+        if plan != Plan::Free {
+            Self::add_credits(e, creator, capacity);
         }
-        if let Some(desc) = description {
-            event_data.description = desc;
+    }
+
+    /// Append an entry to an event's change history, dropping the oldest entry once the
+    /// history holds `MAX_EVENT_CHANGES`. The full history remains in `EventFieldChanged` events.
+    fn record_change(
+        e: &Env,
+        event_id: u32,
+        field: EventField,
+        old_value: u64,
+        new_value: u64,
+        operator: &Address,
+    ) {
+        let key = DataKey::EventChanges(event_id);
+        let mut changes = Self::get_event_changes(e, event_id);
+        if changes.len() >= MAX_EVENT_CHANGES {
+            changes.pop_front();
         }
-        if let Some(uri) = metadata_uri {
-            event_data.metadata_uri = uri;
+        changes.push_back(EventChange {
+            field,
+            old_value,
+            new_value,
+            operator: operator.clone(),
+            changed_at: e.ledger().timestamp(),
+        });
+        storage::write(e, &key, &changes);
+
+        EventFieldChanged {
+            event_id,
+            field,
+            old_value,
+            new_value,
+            operator: operator.clone(),
         }
-        if let Some(img) = image_url {
-            event_data.image_url = img;
+        .publish(e);
+    }
+
+    fn add_credits(e: &Env, creator: &Address, amount: u32) {
+        if amount == 0 {
+            return;
         }
+        let credits = Self::get_credits(e, creator.clone());
+        storage::write(e, &DataKey::CreatorCredits(creator.clone()), &credits.saturating_add(amount));
+    }
 
-        // Save updated event data
-        e.storage().instance().set(&DataKey::EventInfo(event_id), &event_data);
+    fn remove_event_role(e: &Env, event_id: u32, account: &Address) {
+        storage::remove(e, &DataKey::EventRole(event_id, account.clone()));
+
+        let mut delegates = Self::delegate_addresses(e, event_id);
+        if let Some(index) = delegates.first_index_of(account) {
+            delegates.remove(index);
+        }
+        storage::write(e, &DataKey::EventDelegates(event_id), &delegates);
+    }
+
+    fn delegate_addresses(e: &Env, event_id: u32) -> Vec<Address> {
+        storage::read(e, &DataKey::EventDelegates(event_id)).unwrap_or(Vec::new(e))
+    }
 
+    /// Only the contract admin itself, not holders of the admin role
+    fn require_contract_admin(e: &Env, address: &Address) -> Result<(), SpotError> {
+        if *address != Self::admin(e)? {
+            return Err(SpotError::Unauthorized);
+        }
         Ok(())
     }
 
-    // Helper functions for role checking
     fn require_admin(e: &Env, address: &Address) -> Result<(), SpotError> {
         if Self::is_admin_address(e, address)? {
             return Ok(());
@@ -434,6 +2861,33 @@ impl Spot {
         Err(SpotError::Unauthorized)
     }
 
+    fn require_not_paused(e: &Env) -> Result<(), SpotError> {
+        if e.storage().instance().has(&DataKey::Paused) {
+            return Err(SpotError::ContractPaused);
+        }
+        Ok(())
+    }
+
+    fn require_event_not_paused(e: &Env, event_id: u32) -> Result<(), SpotError> {
+        Self::require_not_paused(e)?;
+        if storage::has(e, &DataKey::EventPause(event_id)) {
+            return Err(SpotError::EventPaused);
+        }
+        Ok(())
+    }
+
+    /// Panics if a transfer of `token_id` is blocked by a pause (token interface
+    /// functions cannot return errors)
+    fn require_transferable(e: &Env, token_id: u32) {
+        let paused = match storage::read::<u32>(e, &DataKey::TokenEvent(token_id)) {
+            Some(event_id) => Self::require_event_not_paused(e, event_id),
+            None => Self::require_not_paused(e),
+        };
+        if let Err(error) = paused {
+            panic_with_error!(e, error);
+        }
+    }
+
     fn is_admin_address(e: &Env, address: &Address) -> Result<bool, SpotError> {
         let admin = Self::admin(e)?;
         if *address == admin {
@@ -445,8 +2899,168 @@ impl Spot {
         Ok(false)
     }
 
+    /// Check and consume the commitment to `secret` made by `to` with `commit_claim`
+    fn take_commitment(
+        e: &Env,
+        event_id: u32,
+        to: &Address,
+        secret: &Bytes,
+        salt: BytesN<32>,
+    ) -> Result<(), SpotError> {
+        let mut preimage = secret.clone();
+        preimage.append(&to.clone().to_xdr(e));
+        preimage.append(&salt.into());
+        let commitment: BytesN<32> = e.crypto().sha256(&preimage).into();
+
+        let key = DataKey::ClaimCommit(event_id, commitment);
+        let committed_at: u32 = e
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(SpotError::InvalidCommitment)?;
+
+        let current = e.ledger().sequence();
+        if current <= committed_at {
+            return Err(SpotError::RevealTooEarly);
+        }
+        if current > committed_at.saturating_add(REVEAL_WINDOW_LEDGERS) {
+            return Err(SpotError::InvalidCommitment);
+        }
+
+        e.storage().temporary().remove(&key);
+        Ok(())
+    }
+
+    fn redeem_claim_code(
+        e: &Env,
+        event_id: u32,
+        to: Address,
+        code: Bytes,
+        committed: bool,
+    ) -> Result<u32, SpotError> {
+        let event_data = Self::get_event(e, event_id)?;
+        let mut claim_code: ClaimCode = storage::read(e, &DataKey::ClaimCode(event_id))
+            .ok_or(SpotError::InvalidClaimCode)?;
+
+        let code_hash: BytesN<32> = e.crypto().sha256(&code).into();
+        if code_hash != claim_code.code_hash {
+            return Err(SpotError::InvalidClaimCode);
+        }
+        if claim_code.require_commit && !committed {
+            return Err(SpotError::CommitRequired);
+        }
+        if claim_code.uses_remaining == 0 {
+            return Err(SpotError::ClaimCodeExhausted);
+        }
+
+        let token_id = Self::mint_claim(e, &event_data, to)?;
+
+        claim_code.uses_remaining -= 1;
+        storage::write(e, &DataKey::ClaimCode(event_id), &claim_code);
+
+        Ok(token_id)
+    }
+
+    /// Events with a restricted claim method can only be claimed through it; `claim` and
+    /// `claim_collection` are for open events
+    fn require_open_claim(e: &Env, event_data: &EventData) -> Result<(), SpotError> {
+        let event_id = event_data.event_id;
+        // Invite-only events must be claimed through `claim_allowlisted`
+        if event_data.allowlist_root.is_some() {
+            return Err(SpotError::NotAllowlisted);
+        }
+        // Geofenced events must be claimed through `claim_with_location`
+        if e.storage().persistent().has(&DataKey::Geofence(event_id)) {
+            return Err(SpotError::InvalidAttestation);
+        }
+        // Voucher events must be claimed through `claim_with_voucher`
+        if e.storage().persistent().has(&DataKey::VoucherIssuer(event_id)) {
+            return Err(SpotError::InvalidVoucher);
+        }
+        // Events with a shared code must be claimed through `claim_with_code` or commit–reveal
+        if e.storage().persistent().has(&DataKey::ClaimCode(event_id)) {
+            return Err(SpotError::InvalidClaimCode);
+        }
+        // Events that issued one-time links must be claimed through `reveal_link`
+        if e.storage().persistent().has(&DataKey::LinksRemaining(event_id)) {
+            return Err(SpotError::InvalidClaimLink);
+        }
+        // Events with registered NFC tags must be claimed through `claim_with_nfc`
+        if Self::nfc_tag_count(e, event_id) > 0 {
+            return Err(SpotError::InvalidNfcTag);
+        }
+        Ok(())
+    }
+
+    /// Whether `address` claimed the default badge of an event, including schema 1 claims
+    fn has_claim_record(e: &Env, event_id: u32, address: &Address) -> bool {
+        storage::has(e, &DataKey::HasClaimed(event_id, address.clone()))
+            || migration::has_legacy_claim(e, event_id, address)
+    }
+
     fn has_creator_approval(e: &Env, creator: &Address) -> bool {
-        e.storage().instance().has(&DataKey::CreatorApproval(creator.clone()))
+        storage::has(e, &DataKey::CreatorApproval(creator.clone()))
+    }
+
+    /// Validate the claim window, duplicate and supply rules, then mint a badge for `to`.
+    /// Shared by every claim method.
+    fn mint_claim(e: &Env, event_data: &EventData, to: Address) -> Result<u32, SpotError> {
+        let event_id = event_data.event_id;
+
+        if event_data.status != EventStatus::Published {
+            return Err(SpotError::InvalidEventStatus);
+        }
+        Self::require_event_not_paused(e, event_id)?;
+
+        // Check if claim period is active
+        let current_time = e.ledger().timestamp();
+        if current_time < event_data.claim_start {
+            return Err(SpotError::ClaimPeriodNotStarted);
+        }
+        if current_time > event_data.claim_end {
+            return Err(SpotError::ClaimPeriodEnded);
+        }
+
+        // Check if address has already claimed (prevent duplicates)
+        if Self::has_claim_record(e, event_id, &to) {
+            return Err(SpotError::AlreadyClaimed);
+        }
+
+        // Check if limit is exceeded
+        let minted: u32 = storage::read(e, &DataKey::EventMintedCount(event_id))
+            .unwrap_or(0u32);
+
+        if minted >= event_data.max_poaps {
+            return Err(SpotError::LimitExceeded);
+        }
+
+        let token_id = Self::mint_badge(e, event_id, minted, to);
+
+        // Update counters
+        storage::write(e, &DataKey::EventMintedCount(event_id), &(minted + 1));
+        storage::extend_instance(e);
+
+        Ok(token_id)
+    }
+
+    /// Mint the `index`-th badge of an event to `to` and record the claim.
+    /// Callers validate the claim and update `EventMintedCount` themselves.
+    fn mint_badge(e: &Env, event_id: u32, index: u32, to: Address) -> u32 {
+        let token_id = Enumerable::sequential_mint(e, &to);
+
+        storage::write(e, &DataKey::HasClaimed(event_id, to.clone()), &true);
+        storage::write(e, &DataKey::EventTokenId(event_id, index), &token_id);
+        storage::write(e, &DataKey::TokenEvent(token_id), &event_id);
+        storage::write(e, &DataKey::UserEventTokenId(event_id, to.clone()), &token_id);
+
+        SpotClaimed {
+            event_id,
+            to,
+            token_id,
+        }
+        .publish(e);
+
+        token_id
     }
 }
 
@@ -454,6 +3068,16 @@ impl Spot {
 #[contractimpl]
 impl NonFungibleToken for Spot {
     type ContractType = Enumerable;
+
+    fn transfer(e: &Env, from: Address, to: Address, token_id: u32) {
+        Spot::require_transferable(e, token_id);
+        Enumerable::transfer(e, &from, &to, token_id);
+    }
+
+    fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, token_id: u32) {
+        Spot::require_transferable(e, token_id);
+        Enumerable::transfer_from(e, &spender, &from, &to, token_id);
+    }
 }
 
 #[default_impl]
@@ -466,5 +3090,26 @@ impl NonFungibleBurnable for Spot {}
 
 #[default_impl]
 #[contractimpl]
-impl AccessControl for Spot {}
+impl AccessControl for Spot {
+    /// Admin grants would bypass the timelock; use `grant_admin_role` instead
+    fn grant_role(e: &Env, caller: Address, account: Address, role: Symbol) {
+        if role == symbol_short!("admin") {
+            panic_with_error!(e, SpotError::Unauthorized);
+        }
+        access_control::grant_role(e, &caller, &account, &role);
+    }
+
+    /// The admin role's own admin cannot change, so no other role can grant it
+    fn set_role_admin(e: &Env, role: Symbol, admin_role: Symbol) {
+        if role == symbol_short!("admin") {
+            panic_with_error!(e, SpotError::Unauthorized);
+        }
+        access_control::set_role_admin(e, &role, &admin_role);
+    }
+
+    /// Direct transfers would bypass the timelock; use `set_admin` instead
+    fn transfer_admin_role(e: &Env, _new_admin: Address, _live_until_ledger: u32) {
+        panic_with_error!(e, SpotError::Unauthorized);
+    }
+}
 
@@ -1,4 +1,5 @@
 use soroban_sdk::{contracterror, symbol_short, Symbol};
+use spot_timelock::TimelockError;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -22,6 +23,64 @@ pub enum SpotError {
     EventAlreadyExists = 8,
     /// Creator is missing backend approval metadata
     CreatorNotApproved = 9,
+    /// Invalid voucher: Voucher does not match the event, recipient or issuer
+    InvalidVoucher = 10,
+    /// Voucher expired: Voucher expiry has passed
+    VoucherExpired = 11,
+    /// Voucher already used: Voucher nonce was already redeemed
+    VoucherAlreadyUsed = 12,
+    /// Not allowlisted: Address is not in the event allowlist
+    NotAllowlisted = 13,
+    /// Invalid claim code: Code does not match the event's shared code
+    InvalidClaimCode = 14,
+    /// Claim code exhausted: Shared code has no uses left
+    ClaimCodeExhausted = 15,
+    /// Invalid commitment: Commitment not found, expired or already used
+    InvalidCommitment = 16,
+    /// Reveal too early: Reveal must happen in a later ledger than the commit
+    RevealTooEarly = 17,
+    /// Commit required: Code must be claimed through commit–reveal
+    CommitRequired = 18,
+    /// Invalid claim link: Link is unknown, already used or revoked
+    InvalidClaimLink = 19,
+    /// Claim link expired: Link expiry has passed
+    ClaimLinkExpired = 20,
+    /// Invalid NFC tag: Tag is not registered for the event
+    InvalidNfcTag = 21,
+    /// NFC challenge expired: Challenge nonce is too old or in the future
+    NfcChallengeExpired = 22,
+    /// Invalid attestation: Location attestation is missing, stale or for someone else
+    InvalidAttestation = 23,
+    /// Outside geofence: Attested location is outside the event geofence
+    OutsideGeofence = 24,
+    /// Invalid event status: Action not allowed in the event's current status
+    InvalidEventStatus = 25,
+    /// Collection not found: Collection does not exist for this event
+    CollectionNotFound = 26,
+    /// Plan limit exceeded: Event exceeds the creator's plan limits
+    PlanLimitExceeded = 27,
+    /// Insufficient credits: Creator does not have enough badge credits
+    InsufficientCredits = 28,
+    /// Price not set: No approval price is configured for this token and plan
+    PriceNotSet = 29,
+    /// Donations disabled: Donations are not configured for this token
+    DonationsDisabled = 30,
+    /// Operation not found: Timelocked operation was never scheduled, or already executed or cancelled
+    OperationNotFound = 31,
+    /// Operation not ready: Timelocked operation's ETA has not passed yet
+    OperationNotReady = 32,
+    /// Already signed: Approver already signed this proposal
+    AlreadySigned = 33,
+    /// Contract paused: Claims, minting, transfers and event creation are paused
+    ContractPaused = 34,
+    /// Event paused: Claims, minting and transfers of this event are paused
+    EventPaused = 35,
+    /// Transfer expired: Pending ownership transfer was not accepted in time
+    TransferExpired = 36,
+    /// Already approved: Creator already holds an approval; revoke it before purchasing again
+    CreatorAlreadyApproved = 37,
+    /// Proposal not found: No pending creator approval proposal for this creator and reference
+    ProposalNotFound = 38,
 }
 
 impl SpotError {
@@ -36,7 +95,45 @@ impl SpotError {
             SpotError::EventNotFound => symbol_short!("NO_EVENT"),
             SpotError::EventAlreadyExists => symbol_short!("EVT_EXST"),
             SpotError::CreatorNotApproved => symbol_short!("CRT_APPR"),
+            SpotError::InvalidVoucher => symbol_short!("INV_VCHR"),
+            SpotError::VoucherExpired => symbol_short!("VCHR_EXP"),
+            SpotError::VoucherAlreadyUsed => symbol_short!("VCHR_USED"),
+            SpotError::NotAllowlisted => symbol_short!("NOT_LIST"),
+            SpotError::InvalidClaimCode => symbol_short!("INV_CODE"),
+            SpotError::ClaimCodeExhausted => symbol_short!("CODE_USED"),
+            SpotError::InvalidCommitment => symbol_short!("INV_CMMT"),
+            SpotError::RevealTooEarly => symbol_short!("EARLY_RVL"),
+            SpotError::CommitRequired => symbol_short!("NEED_CMMT"),
+            SpotError::InvalidClaimLink => symbol_short!("INV_LINK"),
+            SpotError::ClaimLinkExpired => symbol_short!("LINK_EXP"),
+            SpotError::InvalidNfcTag => symbol_short!("INV_NFC"),
+            SpotError::NfcChallengeExpired => symbol_short!("NFC_EXP"),
+            SpotError::InvalidAttestation => symbol_short!("INV_ATTST"),
+            SpotError::OutsideGeofence => symbol_short!("OUT_GEO"),
+            SpotError::InvalidEventStatus => symbol_short!("BAD_STATE"),
+            SpotError::CollectionNotFound => symbol_short!("NO_COLL"),
+            SpotError::PlanLimitExceeded => symbol_short!("PLAN_LIM"),
+            SpotError::InsufficientCredits => symbol_short!("NO_CRED"),
+            SpotError::PriceNotSet => symbol_short!("NO_PRICE"),
+            SpotError::DonationsDisabled => symbol_short!("NO_DONATE"),
+            SpotError::OperationNotFound => symbol_short!("NO_OP"),
+            SpotError::OperationNotReady => symbol_short!("OP_LOCKED"),
+            SpotError::AlreadySigned => symbol_short!("SIGNED"),
+            SpotError::ContractPaused => symbol_short!("PAUSED"),
+            SpotError::EventPaused => symbol_short!("EVT_PAUSE"),
+            SpotError::TransferExpired => symbol_short!("XFER_EXP"),
+            SpotError::CreatorAlreadyApproved => symbol_short!("APPROVED"),
+            SpotError::ProposalNotFound => symbol_short!("NO_PROP"),
         }
     }
 }
 
+impl From<TimelockError> for SpotError {
+    fn from(error: TimelockError) -> Self {
+        match error {
+            TimelockError::NotFound => SpotError::OperationNotFound,
+            TimelockError::NotReady => SpotError::OperationNotReady,
+            TimelockError::Rejected => SpotError::InvalidParameters,
+        }
+    }
+}
@@ -0,0 +1,321 @@
+//! SPOT contract events
+//!
+//! Every state change in the SPOT contract publishes one of these events so that
+//! indexers and the backend audit trail can follow the contract without re-reading state.
+//! Topics are fixed: the event name first, then the indexed fields in declaration order.
+
+use soroban_sdk::{contractevent, Address, BytesN, String, Vec};
+
+use crate::contract::{EventField, EventRole, EventStatus, Plan};
+use crate::multisig::ApproverChange;
+
+/// Published when a new event is created
+#[contractevent(topics = ["event_created"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventCreated {
+    #[topic]
+    pub event_id: u32,
+    #[topic]
+    pub creator: Address,
+    pub max_poaps: u32,
+    pub claim_start: u64,
+    pub claim_end: u64,
+}
+
+/// Published when event information is updated
+#[contractevent(topics = ["event_updated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventUpdated {
+    #[topic]
+    pub event_id: u32,
+    pub operator: Address,
+}
+
+/// Published for each guarded edit of an event's capacity or claim window
+#[contractevent(topics = ["event_field_changed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventFieldChanged {
+    #[topic]
+    pub event_id: u32,
+    pub field: EventField,
+    pub old_value: u64,
+    pub new_value: u64,
+    pub operator: Address,
+}
+
+/// Published when a SPOT badge is claimed
+#[contractevent(topics = ["spot_claimed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpotClaimed {
+    #[topic]
+    pub event_id: u32,
+    #[topic]
+    pub to: Address,
+    pub token_id: u32,
+}
+
+/// Published when a claimer commits to a secret claim code or link ID; the secret stays
+/// hidden until the reveal
+#[contractevent(topics = ["claim_committed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimCommitted {
+    #[topic]
+    pub event_id: u32,
+    pub commitment: BytesN<32>,
+}
+
+/// Published with `SpotClaimed` when a geofenced claim passed its location check
+#[contractevent(topics = ["location_verified"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LocationVerified {
+    #[topic]
+    pub event_id: u32,
+    #[topic]
+    pub to: Address,
+    pub latitude: i32,
+    pub longitude: i32,
+    pub attested_at: u64,
+}
+
+/// Published when a creator is approved after payment; `credits` were added to their balance
+#[contractevent(topics = ["creator_approved"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorApproved {
+    #[topic]
+    pub creator: Address,
+    pub operator: Address,
+    pub payment_reference: String,
+    pub plan: Plan,
+    pub credits: u32,
+}
+
+/// Published when the whole contract is paused or unpaused
+#[contractevent(topics = ["pause_changed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseChanged {
+    pub paused: bool,
+    pub operator: Address,
+    pub reason: String,
+}
+
+/// Published when a single event is paused or unpaused
+#[contractevent(topics = ["event_pause_changed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventPauseChanged {
+    #[topic]
+    pub event_id: u32,
+    pub paused: bool,
+    pub operator: Address,
+    pub reason: String,
+}
+
+/// Published when an approver signs a creator approval proposal; the creator is approved
+/// once `signatures` reaches `threshold`
+#[contractevent(topics = ["creator_approval_signed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorApprovalSigned {
+    #[topic]
+    pub creator: Address,
+    pub approver: Address,
+    pub payment_reference: String,
+    pub signatures: u32,
+    pub threshold: u32,
+}
+
+/// Published when an approver cancels a pending creator approval proposal
+#[contractevent(topics = ["creator_proposal_cancelled"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorProposalCancelled {
+    #[topic]
+    pub creator: Address,
+    pub payment_reference: String,
+    pub approver: Address,
+}
+
+/// Published when an approver signs a change to the approver set
+#[contractevent(topics = ["approver_change_signed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApproverChangeSigned {
+    pub change: ApproverChange,
+    pub approver: Address,
+    pub signatures: u32,
+    pub threshold: u32,
+}
+
+/// Published when the approver set is configured or changed
+#[contractevent(topics = ["approvers_updated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApproversUpdated {
+    pub approvers: Vec<Address>,
+    pub threshold: u32,
+}
+
+/// Published when a creator approval is revoked; `refunded` is 0 without a refund
+#[contractevent(topics = ["creator_revoked"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorRevoked {
+    #[topic]
+    pub creator: Address,
+    pub operator: Address,
+    pub refunded: i128,
+}
+
+/// Published when a creator buys an approval on-chain
+#[contractevent(topics = ["approval_purchased"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalPurchased {
+    #[topic]
+    pub creator: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub plan: Plan,
+    pub credits: u32,
+}
+
+/// Published when the approval price of a plan is set or cleared
+#[contractevent(topics = ["approval_price_updated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApprovalPriceUpdated {
+    #[topic]
+    pub token: Address,
+    pub plan: Plan,
+    pub operator: Address,
+}
+
+/// Published when funds are withdrawn from the contract treasury
+#[contractevent(topics = ["treasury_withdrawn"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryWithdrawn {
+    #[topic]
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+    pub operator: Address,
+}
+
+/// Published when the admin role is granted to an address
+#[contractevent(topics = ["admin_granted"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminGranted {
+    #[topic]
+    pub account: Address,
+    pub operator: Address,
+}
+
+/// Published when a new event owner is proposed; `live_until_ledger` 0 cancels the proposal
+#[contractevent(topics = ["event_owner_proposed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventOwnerProposed {
+    #[topic]
+    pub event_id: u32,
+    pub new_owner: Address,
+    pub live_until_ledger: u32,
+    pub operator: Address,
+}
+
+/// Published when the proposed owner accepts an event
+#[contractevent(topics = ["event_owner_changed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventOwnerChanged {
+    #[topic]
+    pub event_id: u32,
+    pub previous_owner: Address,
+    pub new_owner: Address,
+}
+
+/// Published when an event-scoped role is granted
+#[contractevent(topics = ["event_role_granted"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventRoleGranted {
+    #[topic]
+    pub event_id: u32,
+    #[topic]
+    pub account: Address,
+    pub role: EventRole,
+    pub operator: Address,
+}
+
+/// Published when an event-scoped role is revoked
+#[contractevent(topics = ["event_role_revoked"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventRoleRevoked {
+    #[topic]
+    pub event_id: u32,
+    #[topic]
+    pub account: Address,
+    pub operator: Address,
+}
+
+/// Published when an event moves to a new lifecycle status
+#[contractevent(topics = ["event_status_changed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventStatusChanged {
+    #[topic]
+    pub event_id: u32,
+    pub status: EventStatus,
+    pub operator: Address,
+}
+
+/// Published when a badge collection is added to an event
+#[contractevent(topics = ["collection_added"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollectionAdded {
+    #[topic]
+    pub event_id: u32,
+    #[topic]
+    pub collection_id: u32,
+    pub name: String,
+    pub max_supply: u32,
+    pub reserved_supply: u32,
+}
+
+/// Published when a collection badge is minted (public claim or reserved mint)
+#[contractevent(topics = ["collection_badge_minted"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollectionBadgeMinted {
+    #[topic]
+    pub event_id: u32,
+    #[topic]
+    pub collection_id: u32,
+    #[topic]
+    pub to: Address,
+    pub token_id: u32,
+}
+
+/// Published when the donation configuration of a token is set or cleared;
+/// `event_id` is the donor event, or None when donations in `token` are disabled
+#[contractevent(topics = ["donation_config_updated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationConfigUpdated {
+    #[topic]
+    pub token: Address,
+    pub event_id: Option<u32>,
+    pub operator: Address,
+}
+
+/// Published when a donation is received; `total` is the donor's cumulative total in `token`
+#[contractevent(topics = ["donation_received"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationReceived {
+    #[topic]
+    pub donor: Address,
+    #[topic]
+    pub token: Address,
+    pub amount: i128,
+    pub total: i128,
+}
+
+/// Published when the contract code is upgraded
+#[contractevent(topics = ["contract_upgraded"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractUpgraded {
+    pub wasm_hash: BytesN<32>,
+    pub operator: Address,
+}
+
+/// Published when a storage migration completes
+#[contractevent(topics = ["schema_migrated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaMigrated {
+    pub version: u32,
+}
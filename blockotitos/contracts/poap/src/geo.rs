@@ -0,0 +1,48 @@
+//! Integer geodesy helpers for geofenced claims
+//!
+//! Coordinates are fixed-point microdegrees (degrees * 1_000_000). Distances use an
+//! equirectangular approximation, which is accurate to well under 1% for the few
+//! hundred meters a venue geofence covers.
+
+/// Meters per degree of latitude (and of longitude at the equator)
+const METERS_PER_DEGREE: i128 = 111_320;
+/// Fixed-point scale of coordinates and of intermediate trigonometry
+const SCALE: i128 = 1_000_000;
+/// π / 180 scaled by 1e9
+const RADIANS_PER_DEGREE_E9: i128 = 17_453_293;
+
+/// Valid range of a latitude in microdegrees
+pub const MAX_LATITUDE: i32 = 90_000_000;
+/// Valid range of a longitude in microdegrees
+pub const MAX_LONGITUDE: i32 = 180_000_000;
+
+/// cos(x) for x in radians scaled by 1e6, using a Taylor series up to x^6
+fn cos_e6(x: i128) -> i128 {
+    let x2 = x * x / SCALE;
+    let x4 = x2 * x2 / SCALE;
+    let x6 = x4 * x2 / SCALE;
+    SCALE - x2 / 2 + x4 / 24 - x6 / 720
+}
+
+/// Whether `(lat, lon)` lies within `radius_m` meters of `(center_lat, center_lon)`
+pub fn within_radius(center_lat: i32, center_lon: i32, lat: i32, lon: i32, radius_m: u32) -> bool {
+    let d_lat = (lat as i128) - (center_lat as i128);
+    let mut d_lon = (lon as i128) - (center_lon as i128);
+    // Take the short way around the antimeridian
+    if d_lon > MAX_LONGITUDE as i128 {
+        d_lon -= 2 * MAX_LONGITUDE as i128;
+    } else if d_lon < -(MAX_LONGITUDE as i128) {
+        d_lon += 2 * MAX_LONGITUDE as i128;
+    }
+
+    let mean_lat = ((lat as i128) + (center_lat as i128)) / 2;
+    let mean_lat_rad = mean_lat * RADIANS_PER_DEGREE_E9 / 1_000_000_000;
+    let cos_lat = cos_e6(mean_lat_rad).max(0);
+
+    // Offsets in millimeters
+    let dy = d_lat * METERS_PER_DEGREE / 1_000;
+    let dx = d_lon * cos_lat / SCALE * METERS_PER_DEGREE / 1_000;
+    let radius = (radius_m as i128) * 1_000;
+
+    dx * dx + dy * dy <= radius * radius
+}
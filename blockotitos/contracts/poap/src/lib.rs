@@ -2,11 +2,39 @@
 
 mod contract;
 mod error;
+mod events;
+mod geo;
+mod migration;
+mod multisig;
+mod storage;
 
+pub use contract::AirdropResult;
+pub use contract::ApprovalPrice;
+pub use contract::ClaimCode;
+pub use contract::ClaimLink;
+pub use contract::ClaimVoucher;
+pub use contract::Collection;
 pub use contract::CreatorApproval;
+pub use contract::CreatorPayment;
+pub use contract::DonationConfig;
+pub use contract::DonorTier;
 pub use contract::EventData;
+pub use contract::EventChange;
+pub use contract::EventDelegate;
+pub use contract::EventField;
+pub use contract::EventRole;
+pub use contract::EventStatus;
+pub use contract::Geofence;
+pub use contract::LinkStatus;
+pub use contract::LocationAttestation;
+pub use contract::PauseInfo;
+pub use contract::PendingOwner;
+pub use contract::Plan;
 pub use contract::Spot;
 pub use error::SpotError;
+pub use multisig::ApproverChange;
+pub use multisig::ApproverSet;
+pub use multisig::CreatorProposal;
 
 #[cfg(test)]
 mod test;
@@ -0,0 +1,175 @@
+//! Storage schema versions and migrations for the SPOT contract
+//!
+//! Schema 1 is the original layout, where every event and claim record lived in
+//! instance storage. Schema 2 moves per-event records to persistent storage (see
+//! `storage.rs`) and extends `EventData`. `Spot::migrate` walks the events in bounded
+//! batches, keeping a cursor in instance storage so it can resume across transactions.
+//!
+//! Claim records keyed by claimer address (`HasClaimed`, `UserEventTokenId`) cannot be
+//! enumerated, so they stay in instance storage and are read as a fallback. Only
+//! contracts migrated from schema 1 are flagged with `LegacyClaims` and pay for that
+//! fallback read; contracts deployed at schema 2 skip it. Legacy creator approvals must
+//! be re-issued with `approve_creator`.
+
+use soroban_sdk::{contracttype, Address, Env, String};
+
+use crate::contract::{DataKey, EventData, EventStatus};
+use crate::storage;
+
+/// Schema version written by this code
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Schema version of contracts deployed before versioning existed
+const LEGACY_SCHEMA_VERSION: u32 = 1;
+
+/// `EventData` as stored by schema 1
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyEventData {
+    pub event_id: u32,
+    pub creator: Address,
+    pub event_name: String,
+    pub event_date: u64,
+    pub location: String,
+    pub description: String,
+    pub max_poaps: u32,
+    pub claim_start: u64,
+    pub claim_end: u64,
+    pub metadata_uri: String,
+    pub image_url: String,
+}
+
+/// Position of an in-progress migration. `position` 0 is the event header, and
+/// position `n` is the event's badge index `n - 1`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationCursor {
+    pub event_id: u32,
+    pub position: u32,
+}
+
+/// Stored schema version
+pub fn schema_version(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(LEGACY_SCHEMA_VERSION)
+}
+
+pub fn set_schema_version(e: &Env, version: u32) {
+    e.storage().instance().set(&DataKey::SchemaVersion, &version);
+}
+
+/// Migrate up to `batch_size` records (event headers or badge indexes) from schema 1.
+/// Returns true once the migration is complete.
+pub fn migrate_batch(e: &Env, batch_size: u32) -> bool {
+    if schema_version(e) >= SCHEMA_VERSION {
+        return true;
+    }
+
+    let instance = e.storage().instance();
+    instance.set(&DataKey::LegacyClaims, &true);
+    let event_counter: u32 = instance.get(&DataKey::EventCounter).unwrap_or(0u32);
+    let mut cursor: MigrationCursor = instance
+        .get(&DataKey::MigrationCursor)
+        .unwrap_or(MigrationCursor {
+            event_id: 1,
+            position: 0,
+        });
+
+    let mut budget = batch_size;
+    while budget > 0 && cursor.event_id <= event_counter {
+        let event_id = cursor.event_id;
+        if cursor.position == 0 {
+            migrate_event(e, event_id);
+        } else {
+            let minted: u32 =
+                storage::read(e, &DataKey::EventMintedCount(event_id)).unwrap_or(0u32);
+            if cursor.position > minted {
+                cursor = MigrationCursor {
+                    event_id: event_id + 1,
+                    position: 0,
+                };
+                continue;
+            }
+            migrate_token_index(e, event_id, cursor.position - 1);
+        }
+        cursor.position += 1;
+        budget -= 1;
+    }
+
+    if cursor.event_id > event_counter {
+        instance.remove(&DataKey::MigrationCursor);
+        set_schema_version(e, SCHEMA_VERSION);
+        storage::extend_instance(e);
+        return true;
+    }
+
+    instance.set(&DataKey::MigrationCursor, &cursor);
+    false
+}
+
+/// Whether `address` claimed the event under schema 1
+pub fn has_legacy_claim(e: &Env, event_id: u32, address: &Address) -> bool {
+    has_legacy_claims(e)
+        && e.storage()
+            .instance()
+            .has(&DataKey::HasClaimed(event_id, address.clone()))
+}
+
+/// Token claimed by `address` for the event under schema 1
+pub fn legacy_user_token(e: &Env, event_id: u32, address: &Address) -> Option<u32> {
+    if !has_legacy_claims(e) {
+        return None;
+    }
+    e.storage()
+        .instance()
+        .get(&DataKey::UserEventTokenId(event_id, address.clone()))
+}
+
+/// Whether the contract was migrated from schema 1 and may hold legacy claim records
+fn has_legacy_claims(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::LegacyClaims)
+}
+
+fn migrate_event(e: &Env, event_id: u32) {
+    let instance = e.storage().instance();
+    let legacy: Option<LegacyEventData> = instance.get(&DataKey::EventInfo(event_id));
+    let Some(legacy) = legacy else {
+        return;
+    };
+
+    // Schema 1 events went live on creation
+    let event_data = EventData {
+        event_id: legacy.event_id,
+        creator: legacy.creator,
+        event_name: legacy.event_name,
+        event_date: legacy.event_date,
+        location: legacy.location,
+        description: legacy.description,
+        max_poaps: legacy.max_poaps,
+        claim_start: legacy.claim_start,
+        claim_end: legacy.claim_end,
+        metadata_uri: legacy.metadata_uri,
+        image_url: legacy.image_url,
+        allowlist_root: None,
+        status: EventStatus::Published,
+    };
+    let minted: u32 = instance.get(&DataKey::EventMintedCount(event_id)).unwrap_or(0u32);
+
+    storage::write(e, &DataKey::EventInfo(event_id), &event_data);
+    storage::write(e, &DataKey::EventMintedCount(event_id), &minted);
+    instance.remove(&DataKey::EventInfo(event_id));
+    instance.remove(&DataKey::EventMintedCount(event_id));
+}
+
+fn migrate_token_index(e: &Env, event_id: u32, token_index: u32) {
+    let instance = e.storage().instance();
+    let key = DataKey::EventTokenId(event_id, token_index);
+    let token_id: Option<u32> = instance.get(&key);
+    if let Some(token_id) = token_id {
+        storage::write(e, &key, &token_id);
+        storage::write(e, &DataKey::TokenEvent(token_id), &event_id);
+        instance.remove(&key);
+    }
+}
@@ -0,0 +1,109 @@
+//! M-of-N approval of creator onboarding
+//!
+//! Once an approver set is configured, `Spot::approve_creator` no longer grants the
+//! creator role on a single signature. Each approver signs a proposal keyed by
+//! (creator, payment_reference), and the approval is applied when `threshold` current
+//! approvers have signed. Changes to the approver set go through the same process as
+//! `ApproverChange` proposals. Without an approver set, any admin approves alone.
+//! Any approver can cancel a pending creator proposal with
+//! `Spot::cancel_creator_proposal`, discarding its signatures.
+//!
+//! Signatures of approvers removed while a proposal is pending no longer count.
+
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::contract::{DataKey, Plan};
+use crate::error::SpotError;
+use crate::storage;
+
+/// Maximum number of approvers
+pub const MAX_APPROVERS: u32 = 20;
+
+/// Approvers of creator onboarding and the number of signatures required
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApproverSet {
+    pub approvers: Vec<Address>,
+    pub threshold: u32,
+}
+
+/// Change to the approver set, applied once `threshold` approvers have signed it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ApproverChange {
+    Add(Address),
+    Remove(Address),
+    SetThreshold(u32),
+}
+
+/// Pending creator approval and the approvers who signed it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorProposal {
+    pub plan: Plan,
+    pub credits: u32,
+    pub signers: Vec<Address>,
+}
+
+/// Configured approver set, if any
+pub fn approver_set(e: &Env) -> Option<ApproverSet> {
+    storage::read(e, &DataKey::Approvers)
+}
+
+/// Check the set is non-empty, has no duplicates, fits `MAX_APPROVERS` and has a
+/// reachable threshold
+pub fn validate(set: &ApproverSet) -> Result<(), SpotError> {
+    let count = set.approvers.len();
+    if count == 0 || count > MAX_APPROVERS || set.threshold == 0 || set.threshold > count {
+        return Err(SpotError::InvalidParameters);
+    }
+    for (index, approver) in set.approvers.iter().enumerate() {
+        if set.approvers.first_index_of(&approver) != Some(index as u32) {
+            return Err(SpotError::InvalidParameters);
+        }
+    }
+    Ok(())
+}
+
+/// Apply a change to the set and validate the result
+pub fn apply_change(set: &mut ApproverSet, change: &ApproverChange) -> Result<(), SpotError> {
+    match change {
+        ApproverChange::Add(approver) => {
+            if set.approvers.contains(approver) {
+                return Err(SpotError::InvalidParameters);
+            }
+            set.approvers.push_back(approver.clone());
+        }
+        ApproverChange::Remove(approver) => {
+            let index = set
+                .approvers
+                .first_index_of(approver)
+                .ok_or(SpotError::InvalidParameters)?;
+            set.approvers.remove(index);
+        }
+        ApproverChange::SetThreshold(threshold) => set.threshold = *threshold,
+    }
+    validate(set)
+}
+
+/// Add `approver`'s signature to `signers`.
+///
+/// # Returns
+/// The number of signers that are still approvers
+pub fn sign(set: &ApproverSet, signers: &mut Vec<Address>, approver: &Address) -> Result<u32, SpotError> {
+    if !set.approvers.contains(approver) {
+        return Err(SpotError::Unauthorized);
+    }
+    if signers.contains(approver) {
+        return Err(SpotError::AlreadySigned);
+    }
+    signers.push_back(approver.clone());
+
+    let mut signatures = 0u32;
+    for signer in signers.iter() {
+        if set.approvers.contains(&signer) {
+            signatures += 1;
+        }
+    }
+    Ok(signatures)
+}
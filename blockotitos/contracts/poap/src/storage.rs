@@ -0,0 +1,61 @@
+//! Storage helpers for the SPOT contract
+//!
+//! Contract-wide configuration stays in instance storage. Per-event and per-claim
+//! records live in persistent storage, so a claim only touches its own entries instead
+//! of re-writing one ever-growing instance entry. Persistent entries have their TTL
+//! extended whenever they are read or written.
+
+use soroban_sdk::{Env, IntoVal, TryFromVal, Val};
+
+use crate::contract::DataKey;
+
+const DAY_IN_LEDGERS: u32 = 17280;
+
+pub const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// Extend the TTL of the contract instance (and its code)
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+/// Extend the TTL of an existing persistent entry
+pub fn extend(e: &Env, key: &DataKey) {
+    e.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+/// Read a persistent entry, extending its TTL if it exists
+pub fn read<V: TryFromVal<Env, Val>>(e: &Env, key: &DataKey) -> Option<V> {
+    let value = e.storage().persistent().get(key);
+    if value.is_some() {
+        extend(e, key);
+    }
+    value
+}
+
+/// Check whether a persistent entry exists, extending its TTL if it does
+pub fn has(e: &Env, key: &DataKey) -> bool {
+    let exists = e.storage().persistent().has(key);
+    if exists {
+        extend(e, key);
+    }
+    exists
+}
+
+/// Write a persistent entry and extend its TTL
+pub fn write<V: IntoVal<Env, Val>>(e: &Env, key: &DataKey, value: &V) {
+    e.storage().persistent().set(key, value);
+    extend(e, key);
+}
+
+/// Remove a persistent entry
+pub fn remove(e: &Env, key: &DataKey) {
+    e.storage().persistent().remove(key);
+}
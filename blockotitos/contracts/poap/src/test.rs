@@ -1,8 +1,14 @@
 #![cfg(test)]
+extern crate std;
 
 use super::*;
-use crate::contract::SpotClient;
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use crate::contract::{DataKey, SpotClient, MAX_EVENT_CHANGES, REOPEN_GRACE_PERIOD};
+use crate::storage::PERSISTENT_BUMP_AMOUNT;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{storage::Persistent as _, Address as _, Ledger, MockAuth, MockAuthInvoke},
+    token, vec, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Vec,
+};
 
 fn create_client<'a>(e: &'a Env, admin: &Address) -> SpotClient<'a> {
     e.mock_all_auths();
@@ -10,6 +16,24 @@ fn create_client<'a>(e: &'a Env, admin: &Address) -> SpotClient<'a> {
     SpotClient::new(e, &address)
 }
 
+/// Create and publish a claimable test event
+fn create_test_event(e: &Env, client: &SpotClient, creator: &Address, max_poaps: u32) -> u32 {
+    let event_id = client.create_event(
+        creator,
+        &String::from_str(e, "Test Event"),
+        &1735689600u64,
+        &String::from_str(e, "Test Location"),
+        &String::from_str(e, "Test Description"),
+        &max_poaps,
+        &0u64,
+        &u64::MAX,
+        &String::from_str(e, "https://example.com/metadata.json"),
+        &String::from_str(e, "https://example.com/image.png"),
+    );
+    client.publish_event(creator, &event_id);
+    event_id
+}
+
 #[test]
 fn test_constructor() {
     let e = Env::default();
@@ -133,6 +157,7 @@ fn test_claim() {
         &String::from_str(&e, "https://example.com/metadata.json"),
         &String::from_str(&e, "https://example.com/image.png"),
     );
+    client.publish_event(&admin, &event_id);
 
     let token_id = client.claim(&event_id, &recipient);
     assert_eq!(token_id, 0);
@@ -166,6 +191,7 @@ fn test_has_claimed() {
         &String::from_str(&e, "https://example.com/metadata.json"),
         &String::from_str(&e, "https://example.com/image.png"),
     );
+    client.publish_event(&admin, &event_id);
 
     assert!(!client.has_claimed(&event_id, &recipient));
 
@@ -192,6 +218,7 @@ fn test_minted_count() {
         &String::from_str(&e, "https://example.com/metadata.json"),
         &String::from_str(&e, "https://example.com/image.png"),
     );
+    client.publish_event(&admin, &event_id);
 
     let minted = client.minted_count(&event_id);
     assert_eq!(minted, 0);
@@ -226,6 +253,7 @@ fn test_limit_exceeded() {
         &String::from_str(&e, "https://example.com/metadata.json"),
         &String::from_str(&e, "https://example.com/image.png"),
     );
+    client.publish_event(&admin, &event_id);
 
     let recipient1 = Address::generate(&e);
     let recipient2 = Address::generate(&e);
@@ -327,10 +355,16 @@ fn test_get_all_events() {
         &String::from_str(&e, "https://example.com/image2.png"),
     );
 
-    let all_events = client.get_all_events();
+    let all_events = client.get_all_events(&None, &0, &10);
     assert_eq!(all_events.len(), 2);
     assert_eq!(all_events.get(0).unwrap(), event_id1);
     assert_eq!(all_events.get(1).unwrap(), event_id2);
+
+    // Pages scan at most `limit` event IDs
+    assert_eq!(client.get_all_events(&None, &0, &1), vec![&e, event_id1]);
+    assert_eq!(client.get_all_events(&None, &1, &1), vec![&e, event_id2]);
+    assert_eq!(client.get_all_events(&None, &2, &10).len(), 0);
+    assert_eq!(client.get_all_events(&None, &u32::MAX, &u32::MAX).len(), 0);
 }
 
 #[test]
@@ -359,6 +393,8 @@ fn test_creator_requires_backend_approval() {
         &admin,
         &creator,
         &String::from_str(&e, "invoice-123"),
+        &Plan::Pro,
+        &100u32,
     );
 
     let event_id = client.create_event(
@@ -392,6 +428,8 @@ fn test_revoke_creator_approval_blocks_future_events() {
         &admin,
         &creator,
         &String::from_str(&e, "invoice-456"),
+        &Plan::Pro,
+        &100u32,
     );
 
     client.create_event(
@@ -407,7 +445,7 @@ fn test_revoke_creator_approval_blocks_future_events() {
         &String::from_str(&e, "https://example.com/image.png"),
     );
 
-    client.revoke_creator_approval(&admin, &creator);
+    client.revoke_creator_approval(&admin, &creator, &false);
 
     let result = client.try_create_event(
         &creator,
@@ -424,3 +462,1483 @@ fn test_revoke_creator_approval_blocks_future_events() {
 
     assert_eq!(result.unwrap_err(), Ok(SpotError::CreatorNotApproved));
 }
+
+#[test]
+fn test_claims_use_persistent_storage() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let event_id = create_test_event(&e, &client, &admin, 10);
+    client.claim(&event_id, &recipient);
+
+    e.as_contract(&client.address, || {
+        assert!(e.storage().persistent().has(&DataKey::EventInfo(event_id)));
+        assert!(e.storage().persistent().has(&DataKey::HasClaimed(event_id, recipient.clone())));
+        assert!(e.storage().persistent().has(&DataKey::UserEventTokenId(event_id, recipient.clone())));
+        assert!(!e.storage().instance().has(&DataKey::HasClaimed(event_id, recipient.clone())));
+    });
+}
+
+#[test]
+fn test_bump_event_extends_ttl() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let event_id = create_test_event(&e, &client, &admin, 10);
+    let minter = Address::generate(&e);
+    client.set_voucher_issuer(&admin, &event_id, &BytesN::from_array(&e, &[7u8; 32]));
+    client.grant_event_role(&admin, &event_id, &minter, &EventRole::Minter);
+    let keys = [
+        DataKey::EventInfo(event_id),
+        DataKey::VoucherIssuer(event_id),
+        DataKey::EventDelegates(event_id),
+        DataKey::EventRole(event_id, minter),
+    ];
+
+    // Let two days of ledgers pass so the entries fall below the bump threshold
+    e.ledger().with_mut(|li| li.sequence_number += 2 * 17280);
+    for key in keys.iter() {
+        let ttl_before = e.as_contract(&client.address, || e.storage().persistent().get_ttl(key));
+        assert!(ttl_before < PERSISTENT_BUMP_AMOUNT);
+    }
+
+    client.bump_event(&event_id);
+
+    for key in keys.iter() {
+        let ttl_after = e.as_contract(&client.address, || e.storage().persistent().get_ttl(key));
+        assert_eq!(ttl_after, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    let result = client.try_bump_event(&999);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::EventNotFound));
+}
+
+fn sign_voucher(e: &Env, client: &SpotClient, key: &SigningKey, voucher: &ClaimVoucher) -> BytesN<64> {
+    let payload: std::vec::Vec<u8> = client.voucher_payload(voucher).iter().collect();
+    BytesN::from_array(e, &key.sign(&payload).to_bytes())
+}
+
+#[test]
+fn test_claim_with_voucher() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let issuer = SigningKey::from_bytes(&[7u8; 32]);
+
+    let event_id = create_test_event(&e, &client, &admin, 10);
+    client.set_voucher_issuer(
+        &admin,
+        &event_id,
+        &BytesN::from_array(&e, &issuer.verifying_key().to_bytes()),
+    );
+
+    // Open claims are closed once vouchers are required
+    let result = client.try_claim(&event_id, &recipient);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidVoucher));
+
+    let voucher = ClaimVoucher {
+        event_id,
+        recipient: None,
+        expires_at: 1000,
+        nonce: 1,
+    };
+    let signature = sign_voucher(&e, &client, &issuer, &voucher);
+
+    let token_id = client.claim_with_voucher(&event_id, &recipient, &voucher, &signature);
+    assert_eq!(token_id, 0);
+    assert!(client.has_claimed(&event_id, &recipient));
+
+    // The same nonce cannot be redeemed twice, even by another recipient
+    let result = client.try_claim_with_voucher(&event_id, &Address::generate(&e), &voucher, &signature);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::VoucherAlreadyUsed));
+}
+
+#[test]
+fn test_claim_with_voucher_rejects_invalid_vouchers() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let issuer = SigningKey::from_bytes(&[7u8; 32]);
+    let forger = SigningKey::from_bytes(&[9u8; 32]);
+
+    let event_id = create_test_event(&e, &client, &admin, 10);
+    client.set_voucher_issuer(
+        &admin,
+        &event_id,
+        &BytesN::from_array(&e, &issuer.verifying_key().to_bytes()),
+    );
+
+    // Voucher bound to another recipient
+    let voucher = ClaimVoucher {
+        event_id,
+        recipient: Some(Address::generate(&e)),
+        expires_at: 1000,
+        nonce: 1,
+    };
+    let signature = sign_voucher(&e, &client, &issuer, &voucher);
+    let result = client.try_claim_with_voucher(&event_id, &recipient, &voucher, &signature);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidVoucher));
+
+    // Expired voucher
+    e.ledger().with_mut(|li| li.timestamp = 2000);
+    let voucher = ClaimVoucher {
+        event_id,
+        recipient: None,
+        expires_at: 1000,
+        nonce: 2,
+    };
+    let signature = sign_voucher(&e, &client, &issuer, &voucher);
+    let result = client.try_claim_with_voucher(&event_id, &recipient, &voucher, &signature);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::VoucherExpired));
+
+    // Voucher signed by a key other than the registered issuer
+    let voucher = ClaimVoucher {
+        event_id,
+        recipient: None,
+        expires_at: 5000,
+        nonce: 3,
+    };
+    let signature = sign_voucher(&e, &client, &forger, &voucher);
+    let result = client.try_claim_with_voucher(&event_id, &recipient, &voucher, &signature);
+    assert!(result.is_err());
+    assert!(!client.has_claimed(&event_id, &recipient));
+}
+
+fn allowlist_leaf(e: &Env, address: &Address) -> BytesN<32> {
+    e.crypto().sha256(&address.clone().to_xdr(e)).into()
+}
+
+fn allowlist_parent(e: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a.to_array() <= b.to_array() { (a, b) } else { (b, a) };
+    let mut pair = Bytes::from(first);
+    pair.append(&Bytes::from(second));
+    e.crypto().sha256(&pair).into()
+}
+
+#[test]
+fn test_claim_allowlisted() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let attendee1 = Address::generate(&e);
+    let attendee2 = Address::generate(&e);
+    let outsider = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let event_id = create_test_event(&e, &client, &admin, 10);
+
+    let leaf1 = allowlist_leaf(&e, &attendee1);
+    let leaf2 = allowlist_leaf(&e, &attendee2);
+    let root = allowlist_parent(&e, &leaf1, &leaf2);
+    client.set_allowlist_root(&admin, &event_id, &Some(root.clone()));
+    assert_eq!(client.get_event(&event_id).allowlist_root, Some(root));
+
+    // Open claims are closed for invite-only events
+    let result = client.try_claim(&event_id, &attendee1);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::NotAllowlisted));
+
+    let token_id = client.claim_allowlisted(&event_id, &attendee1, &vec![&e, leaf2.clone()]);
+    assert_eq!(token_id, 0);
+    assert!(client.has_claimed(&event_id, &attendee1));
+
+    // A valid proof for someone else does not work for the outsider
+    let result = client.try_claim_allowlisted(&event_id, &outsider, &vec![&e, leaf1]);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::NotAllowlisted));
+
+    // Clearing the root reopens the event to regular claims
+    client.set_allowlist_root(&admin, &event_id, &None);
+    client.claim(&event_id, &outsider);
+    assert!(client.has_claimed(&event_id, &outsider));
+}
+
+#[test]
+fn test_claim_with_code() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let event_id = create_test_event(&e, &client, &admin, 10);
+    let code = Bytes::from_slice(&e, b"STELLAR-2024");
+    client.set_claim_code(&admin, &event_id, &e.crypto().sha256(&code).into(), &2u32, &false);
+    assert_eq!(client.claim_code_uses(&event_id), 2);
+
+    let attendee1 = Address::generate(&e);
+    let attendee2 = Address::generate(&e);
+    let attendee3 = Address::generate(&e);
+
+    // Open claims would bypass the code and its use limit
+    let result = client.try_claim(&event_id, &attendee1);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidClaimCode));
+
+    let result = client.try_claim_with_code(&event_id, &attendee1, &Bytes::from_slice(&e, b"WRONG"));
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidClaimCode));
+
+    client.claim_with_code(&event_id, &attendee1, &code);
+    assert_eq!(client.claim_code_uses(&event_id), 1);
+
+    // One badge per wallet still applies and does not consume a use
+    let result = client.try_claim_with_code(&event_id, &attendee1, &code);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::AlreadyClaimed));
+    assert_eq!(client.claim_code_uses(&event_id), 1);
+
+    client.claim_with_code(&event_id, &attendee2, &code);
+    assert_eq!(client.claim_code_uses(&event_id), 0);
+
+    let result = client.try_claim_with_code(&event_id, &attendee3, &code);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::ClaimCodeExhausted));
+}
+
+fn claim_commitment(e: &Env, secret: &Bytes, to: &Address, salt: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = secret.clone();
+    preimage.append(&to.clone().to_xdr(e));
+    preimage.append(&Bytes::from(salt));
+    e.crypto().sha256(&preimage).into()
+}
+
+#[test]
+fn test_commit_reveal_claim() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let attendee = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let event_id = create_test_event(&e, &client, &admin, 10);
+    let code = Bytes::from_slice(&e, b"CROWDED-CONF");
+    client.set_claim_code(&admin, &event_id, &e.crypto().sha256(&code).into(), &5u32, &true);
+
+    // Protected codes cannot be claimed in a single, front-runnable transaction
+    let result = client.try_claim_with_code(&event_id, &attendee, &code);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::CommitRequired));
+
+    let salt = BytesN::from_array(&e, &[3u8; 32]);
+    client.commit_claim(&event_id, &claim_commitment(&e, &code, &attendee, &salt));
+
+    // Same ledger reveal is rejected
+    let result = client.try_reveal_claim(&event_id, &attendee, &code, &salt);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::RevealTooEarly));
+
+    e.ledger().with_mut(|li| li.sequence_number += 1);
+
+    // A copycat revealing the code for themselves has no matching commitment
+    let copycat = Address::generate(&e);
+    let result = client.try_reveal_claim(&event_id, &copycat, &code, &salt);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidCommitment));
+
+    client.reveal_claim(&event_id, &attendee, &code, &salt);
+    assert!(client.has_claimed(&event_id, &attendee));
+    assert_eq!(client.claim_code_uses(&event_id), 4);
+
+    // The commitment is consumed by the reveal
+    let result = client.try_reveal_claim(&event_id, &attendee, &code, &salt);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidCommitment));
+}
+
+#[test]
+fn test_claim_with_link_commit_reveal() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let event_id = create_test_event(&e, &client, &admin, 10);
+    let link1 = Bytes::from_slice(&e, b"link-1");
+    let link2 = Bytes::from_slice(&e, b"link-2");
+    let link3 = Bytes::from_slice(&e, b"link-3");
+    let hashes = vec![
+        &e,
+        e.crypto().sha256(&link1).into(),
+        e.crypto().sha256(&link2).into(),
+        e.crypto().sha256(&link3).into(),
+    ];
+
+    // Free plan links expire within a week
+    let result = client.try_register_links(&admin, &event_id, &hashes, &(1000 + 8 * 24 * 60 * 60));
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidParameters));
+
+    // Minter delegates register batches; only managers revoke them
+    let minter = Address::generate(&e);
+    client.grant_event_role(&admin, &event_id, &minter, &EventRole::Minter);
+    client.register_links(&minter, &event_id, &hashes, &5000u64);
+    assert_eq!(client.links_remaining(&event_id), 3);
+    let result = client.try_revoke_links(&minter, &event_id, &hashes);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+
+    // Open claims would bypass the links
+    let result = client.try_claim(&event_id, &Address::generate(&e));
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidClaimLink));
+    let result = client.try_claim_collection(&event_id, &1, &Address::generate(&e));
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidClaimLink));
+
+    // Links are claimed through commit–reveal so they cannot be front-run
+    let salt = BytesN::from_array(&e, &[4u8; 32]);
+    let claim_link = |to: &Address, link: &Bytes| {
+        client.commit_claim(&event_id, &claim_commitment(&e, link, to, &salt));
+        e.ledger().with_mut(|li| li.sequence_number += 1);
+        client.try_reveal_link(&event_id, to, link, &salt)
+    };
+
+    let attendee1 = Address::generate(&e);
+    let attendee2 = Address::generate(&e);
+    claim_link(&attendee1, &link1).unwrap().unwrap();
+    assert!(client.has_claimed(&event_id, &attendee1));
+    assert_eq!(client.links_remaining(&event_id), 2);
+
+    // A consumed link can never be used again, nor registered again
+    let result = claim_link(&attendee2, &link1);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidClaimLink));
+    let result = client.try_register_links(&admin, &event_id, &vec![&e, e.crypto().sha256(&link1).into()], &5000u64);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidClaimLink));
+
+    client.revoke_links(&admin, &event_id, &vec![&e, e.crypto().sha256(&link2).into()]);
+    assert_eq!(client.links_remaining(&event_id), 1);
+    let result = claim_link(&attendee2, &link2);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidClaimLink));
+
+    e.ledger().with_mut(|li| li.timestamp = 6000);
+    let result = claim_link(&attendee2, &link3);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::ClaimLinkExpired));
+}
+
+#[test]
+fn test_claim_with_nfc() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let attendee = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    e.ledger().with_mut(|li| li.sequence_number = 1000);
+
+    let event_id = create_test_event(&e, &client, &admin, 10);
+    let tag = SigningKey::from_bytes(&[5u8; 32]);
+    let tag_key = BytesN::from_array(&e, &tag.verifying_key().to_bytes());
+    let sign_challenge = |to: &Address, nonce_ledger: u32| {
+        let payload: std::vec::Vec<u8> =
+            client.nfc_payload(&event_id, to, &nonce_ledger).iter().collect();
+        BytesN::from_array(&e, &tag.sign(&payload).to_bytes())
+    };
+
+    // Unregistered tag
+    let signature = sign_challenge(&attendee, 1000);
+    let result = client.try_claim_with_nfc(&event_id, &attendee, &tag_key, &1000u32, &signature);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidNfcTag));
+
+    client.register_nfc_tags(&admin, &event_id, &vec![&e, tag_key.clone(), tag_key.clone()]);
+    assert!(client.is_nfc_tag(&event_id, &tag_key));
+    assert_eq!(client.nfc_tag_count(&event_id), 1);
+
+    // Tagless claims are closed while tags are registered
+    let result = client.try_claim(&event_id, &Address::generate(&e));
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidNfcTag));
+
+    // Stale challenge
+    e.ledger().with_mut(|li| li.sequence_number = 1100);
+    let result = client.try_claim_with_nfc(&event_id, &attendee, &tag_key, &1000u32, &signature);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::NfcChallengeExpired));
+
+    // A signature for one attendee cannot be replayed by another
+    let signature = sign_challenge(&attendee, 1090);
+    let other = Address::generate(&e);
+    let result = client.try_claim_with_nfc(&event_id, &other, &tag_key, &1090u32, &signature);
+    assert!(result.is_err());
+
+    client.claim_with_nfc(&event_id, &attendee, &tag_key, &1090u32, &signature);
+    assert!(client.has_claimed(&event_id, &attendee));
+
+    client.revoke_nfc_tag(&admin, &event_id, &tag_key);
+    assert!(!client.is_nfc_tag(&event_id, &tag_key));
+    assert_eq!(client.nfc_tag_count(&event_id), 0);
+}
+
+#[test]
+fn test_claim_with_location() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let attendee = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    e.ledger().with_mut(|li| li.timestamp = 10_000);
+
+    let event_id = create_test_event(&e, &client, &admin, 10);
+    let attestor = SigningKey::from_bytes(&[11u8; 32]);
+    // Bogotá, 200 m radius
+    client.set_geofence(
+        &admin,
+        &event_id,
+        &Some(Geofence {
+            latitude: 4_609_700,
+            longitude: -74_081_700,
+            radius_m: 200,
+            attestor: BytesN::from_array(&e, &attestor.verifying_key().to_bytes()),
+        }),
+    );
+
+    // Plain claims are not accepted for geofenced events
+    let result = client.try_claim(&event_id, &attendee);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidAttestation));
+
+    let sign = |attestation: &LocationAttestation| {
+        let payload: std::vec::Vec<u8> = client.attestation_payload(attestation).iter().collect();
+        BytesN::from_array(&e, &attestor.sign(&payload).to_bytes())
+    };
+
+    // ~1 km north of the centre
+    let far = LocationAttestation {
+        event_id,
+        claimer: attendee.clone(),
+        latitude: 4_618_700,
+        longitude: -74_081_700,
+        timestamp: 9_990,
+    };
+    let result = client.try_claim_with_location(&event_id, &attendee, &far, &sign(&far));
+    assert_eq!(result.unwrap_err(), Ok(SpotError::OutsideGeofence));
+
+    // Stale attestation
+    let stale = LocationAttestation {
+        latitude: 4_609_800,
+        timestamp: 1_000,
+        ..far.clone()
+    };
+    let result = client.try_claim_with_location(&event_id, &attendee, &stale, &sign(&stale));
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidAttestation));
+
+    // ~100 m north-east of the centre
+    let near = LocationAttestation {
+        latitude: 4_610_300,
+        longitude: -74_081_000,
+        ..far
+    };
+    client.claim_with_location(&event_id, &attendee, &near, &sign(&near));
+    assert!(client.has_claimed(&event_id, &attendee));
+}
+
+#[test]
+fn test_claim_requires_recipient_auth() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let event_id = create_test_event(&e, &client, &admin, 10);
+
+    // Enforce real authorizations from here on
+    e.set_auths(&[]);
+    assert!(client.try_claim(&event_id, &recipient).is_err());
+
+    e.mock_auths(&[MockAuth {
+        address: &recipient,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "claim",
+            args: (event_id, recipient.clone()).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    client.claim(&event_id, &recipient);
+    assert!(client.has_claimed(&event_id, &recipient));
+}
+
+#[test]
+fn test_claim_sponsored_requires_minter_auth() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let attacker = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let event_id = create_test_event(&e, &client, &admin, 10);
+
+    e.mock_auths(&[MockAuth {
+        address: &attacker,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "claim_sponsored",
+            args: (attacker.clone(), event_id, recipient.clone()).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    let result = client.try_claim_sponsored(&attacker, &event_id, &recipient);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+
+    // The creator sponsors the claim; the recipient signs nothing
+    e.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "claim_sponsored",
+            args: (admin.clone(), event_id, recipient.clone()).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    client.claim_sponsored(&admin, &event_id, &recipient);
+    assert!(client.has_claimed(&event_id, &recipient));
+}
+
+#[test]
+fn test_update_event_requires_operator_auth() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let attacker = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let event_id = create_test_event(&e, &client, &admin, 10);
+    let new_name = String::from_str(&e, "Hijacked");
+
+    // Passing the creator's address without the creator's signature fails
+    e.set_auths(&[]);
+    let result = client.try_update_event(
+        &admin, &event_id, &Some(new_name.clone()), &None, &None, &None, &None, &None,
+    );
+    assert!(result.is_err());
+
+    // Signing as yourself does not make you the creator
+    e.mock_auths(&[MockAuth {
+        address: &attacker,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "update_event",
+            args: (
+                attacker.clone(),
+                event_id,
+                Some(new_name.clone()),
+                None::<u64>,
+                None::<String>,
+                None::<String>,
+                None::<String>,
+                None::<String>,
+            )
+                .into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    let result = client.try_update_event(
+        &attacker, &event_id, &Some(new_name.clone()), &None, &None, &None, &None, &None,
+    );
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+
+    assert_eq!(client.get_event(&event_id).event_name, String::from_str(&e, "Test Event"));
+}
+
+#[test]
+fn test_grant_admin_role_requires_contract_admin() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let attacker = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    e.mock_auths(&[MockAuth {
+        address: &attacker,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "grant_admin_role",
+            args: (attacker.clone(), attacker.clone()).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    let result = client.try_grant_admin_role(&attacker, &attacker);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+}
+
+#[test]
+fn test_access_control_cannot_grant_admin_directly() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let other = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let admin_role = Symbol::new(&e, "admin");
+    assert!(client.try_grant_role(&admin, &other, &admin_role).is_err());
+    assert!(client.try_set_role_admin(&admin_role, &Symbol::new(&e, "creator")).is_err());
+    assert_eq!(client.has_role(&other, &admin_role), None);
+
+    // Other roles still go through the default access control
+    let creator_role = Symbol::new(&e, "creator");
+    client.grant_role(&admin, &other, &creator_role);
+    assert!(client.has_role(&other, &creator_role).is_some());
+}
+
+#[test]
+fn test_event_scoped_roles() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let creator = Address::generate(&e);
+    let volunteer = Address::generate(&e);
+    let organizer = Address::generate(&e);
+    let attendee = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    client.approve_creator(&admin, &creator, &String::from_str(&e, "payment-1"), &Plan::Pro, &100u32);
+    let event_id = create_test_event(&e, &client, &creator, 10);
+    let other_event_id = create_test_event(&e, &client, &creator, 10);
+
+    client.grant_event_role(&creator, &event_id, &volunteer, &EventRole::Minter);
+    client.grant_event_role(&creator, &event_id, &organizer, &EventRole::Manager);
+    assert_eq!(client.get_event_role(&event_id, &volunteer), Some(EventRole::Minter));
+    assert_eq!(client.get_event_role(&other_event_id, &volunteer), None);
+
+    let delegates = client.get_event_delegates(&event_id);
+    assert_eq!(delegates.len(), 2);
+    assert_eq!(
+        delegates.get(0).unwrap(),
+        EventDelegate { account: volunteer.clone(), role: EventRole::Minter }
+    );
+
+    // Minters can sponsor claims for their event only
+    client.claim_sponsored(&volunteer, &event_id, &attendee);
+    assert!(client.has_claimed(&event_id, &attendee));
+    let result = client.try_claim_sponsored(&volunteer, &other_event_id, &attendee);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+
+    // Only managers can update the event
+    let new_description = String::from_str(&e, "Moved to room B");
+    let result = client.try_update_event(
+        &volunteer, &event_id, &None, &None, &None, &Some(new_description.clone()), &None, &None,
+    );
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+    client.update_event(
+        &organizer, &event_id, &None, &None, &None, &Some(new_description.clone()), &None, &None,
+    );
+    assert_eq!(client.get_event(&event_id).description, new_description);
+
+    // Delegates cannot hand out roles themselves
+    let result = client.try_grant_event_role(&organizer, &event_id, &attendee, &EventRole::Manager);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+
+    client.revoke_event_role(&creator, &event_id, &volunteer);
+    assert_eq!(client.get_event_role(&event_id, &volunteer), None);
+    assert_eq!(client.get_event_delegates(&event_id).len(), 1);
+    let result = client.try_claim_sponsored(&volunteer, &event_id, &Address::generate(&e));
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+}
+
+#[test]
+fn test_airdrop() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let volunteer = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let event_id = create_test_event(&e, &client, &admin, 3);
+    client.grant_event_role(&admin, &event_id, &volunteer, &EventRole::Minter);
+
+    let early = Address::generate(&e);
+    client.claim(&event_id, &early);
+
+    let first = Address::generate(&e);
+    let second = Address::generate(&e);
+    let late = Address::generate(&e);
+    let recipients = vec![&e, first.clone(), early.clone(), second.clone(), first.clone(), late.clone()];
+    let results = client.airdrop(&event_id, &volunteer, &recipients);
+
+    assert_eq!(results.len(), 5);
+    assert!(matches!(results.get(0).unwrap(), AirdropResult::Minted(_)));
+    assert_eq!(results.get(1).unwrap(), AirdropResult::AlreadyClaimed);
+    assert!(matches!(results.get(2).unwrap(), AirdropResult::Minted(_)));
+    assert_eq!(results.get(3).unwrap(), AirdropResult::AlreadyClaimed);
+    assert_eq!(results.get(4).unwrap(), AirdropResult::LimitExceeded);
+
+    assert_eq!(client.minted_count(&event_id), 3);
+    assert!(client.has_claimed(&event_id, &second));
+    assert!(!client.has_claimed(&event_id, &late));
+    assert_eq!(client.get_event_poaps(&event_id).len(), 3);
+
+    let outsider = Address::generate(&e);
+    let result = client.try_airdrop(&event_id, &outsider, &vec![&e, late]);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+    let result = client.try_airdrop(&event_id, &admin, &Vec::new(&e));
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidParameters));
+}
+
+#[test]
+fn test_event_lifecycle() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let attendee = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let event_id = client.create_event(
+        &admin,
+        &String::from_str(&e, "Draft Event"),
+        &1735689600u64,
+        &String::from_str(&e, "Test Location"),
+        &String::from_str(&e, "Test Description"),
+        &10u32,
+        &0u64,
+        &u64::MAX,
+        &String::from_str(&e, "https://example.com/metadata.json"),
+        &String::from_str(&e, "https://example.com/image.png"),
+    );
+    assert_eq!(client.get_event(&event_id).status, EventStatus::Draft);
+
+    // Drafts are editable but not claimable
+    let result = client.try_claim(&event_id, &attendee);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidEventStatus));
+    let new_name = String::from_str(&e, "Final Name");
+    client.update_event(&admin, &event_id, &Some(new_name.clone()), &None, &None, &None, &None, &None);
+
+    // Publishing freezes the name, date and metadata URI
+    client.publish_event(&admin, &event_id);
+    let token_id = client.claim(&event_id, &attendee);
+    let result = client.try_update_event(
+        &admin, &event_id, &Some(String::from_str(&e, "Other")), &None, &None, &None, &None, &None,
+    );
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidEventStatus));
+    let result = client.try_publish_event(&admin, &event_id);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidEventStatus));
+
+    // Closing freezes supply
+    let closed_id = create_test_event(&e, &client, &admin, 10);
+    client.close_event(&admin, &closed_id);
+    let result = client.try_claim(&closed_id, &attendee);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidEventStatus));
+    let result = client.try_cancel_event(&admin, &closed_id);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidEventStatus));
+
+    // Cancelling blocks claims and marks minted badges
+    assert!(!client.is_badge_cancelled(&token_id));
+    client.cancel_event(&admin, &event_id);
+    assert!(client.is_badge_cancelled(&token_id));
+    assert_eq!(client.get_badge_event(&token_id), event_id);
+    let result = client.try_claim(&event_id, &Address::generate(&e));
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidEventStatus));
+    assert_eq!(client.get_event(&event_id).event_name, new_name);
+
+    // Listing filtered by status
+    let draft_id = client.create_event(
+        &admin,
+        &String::from_str(&e, "Another Draft"),
+        &1735689600u64,
+        &String::from_str(&e, "Test Location"),
+        &String::from_str(&e, "Test Description"),
+        &10u32,
+        &0u64,
+        &u64::MAX,
+        &String::from_str(&e, "https://example.com/metadata.json"),
+        &String::from_str(&e, "https://example.com/image.png"),
+    );
+    assert_eq!(client.get_all_events(&None, &0, &50).len(), 3);
+    assert_eq!(client.get_all_events(&Some(EventStatus::Draft), &0, &50), vec![&e, draft_id]);
+    assert_eq!(client.get_all_events(&Some(EventStatus::Closed), &0, &50), vec![&e, closed_id]);
+    assert_eq!(client.get_all_events(&Some(EventStatus::Cancelled), &0, &50), vec![&e, event_id]);
+    assert_eq!(client.get_all_events(&Some(EventStatus::Published), &0, &50).len(), 0);
+}
+
+#[test]
+fn test_collections() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let attendee1 = Address::generate(&e);
+    let attendee2 = Address::generate(&e);
+    let speaker = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let event_id = create_test_event(&e, &client, &admin, 10);
+
+    let collection_id = client.add_collection(
+        &admin,
+        &event_id,
+        &String::from_str(&e, "Speaker"),
+        &1u32,
+        &1u32,
+        &0u64,
+        &u64::MAX,
+        &String::from_str(&e, "https://example.com/speaker.json"),
+        &String::from_str(&e, "https://example.com/speaker.png"),
+    );
+    assert_eq!(collection_id, 1);
+    assert_eq!(client.collection_count(&event_id), 1);
+
+    // Collection badges are separate from the default event badge
+    let default_token = client.claim(&event_id, &attendee1);
+    let token_id = client.claim_collection(&event_id, &collection_id, &attendee1);
+    assert_eq!(client.get_badge_collection(&default_token), 0);
+    assert_eq!(client.get_badge_collection(&token_id), collection_id);
+    assert_eq!(client.get_badge_event(&token_id), event_id);
+    assert!(client.has_claimed_collection(&event_id, &collection_id, &attendee1));
+    assert_eq!(client.minted_count(&event_id), 1);
+
+    let result = client.try_claim_collection(&event_id, &collection_id, &attendee1);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::AlreadyClaimed));
+
+    // Public cap reached, but the reserved supply is still available to organizers
+    let result = client.try_claim_collection(&event_id, &collection_id, &attendee2);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::LimitExceeded));
+    client.mint_reserved(&admin, &event_id, &collection_id, &speaker);
+    let result = client.try_mint_reserved(&admin, &event_id, &collection_id, &attendee2);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::LimitExceeded));
+
+    let collection = client.get_collection(&event_id, &collection_id);
+    assert_eq!(collection.minted, 1);
+    assert_eq!(collection.reserved_minted, 1);
+
+    let result = client.try_claim_collection(&event_id, &2, &attendee2);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::CollectionNotFound));
+    let result = client.try_mint_reserved(&attendee2, &event_id, &collection_id, &attendee2);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+}
+
+#[test]
+fn test_plan_limits_and_credits() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let free_creator = Address::generate(&e);
+    let paid_creator = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let week = 7 * 24 * 60 * 60;
+
+    let create = |creator: &Address, max_poaps: u32, claim_end: u64| {
+        client.try_create_event(
+            creator,
+            &String::from_str(&e, "Plan Event"),
+            &1735689600u64,
+            &String::from_str(&e, "Test Location"),
+            &String::from_str(&e, "Test Description"),
+            &max_poaps,
+            &0u64,
+            &claim_end,
+            &String::from_str(&e, "https://example.com/metadata.json"),
+            &String::from_str(&e, "https://example.com/image.png"),
+        )
+    };
+
+    // Free plan: at most 100 badges and a one-week claim window
+    client.approve_creator(&admin, &free_creator, &String::from_str(&e, "free"), &Plan::Free, &0u32);
+    assert_eq!(create(&free_creator, 101, week).unwrap_err(), Ok(SpotError::PlanLimitExceeded));
+    assert_eq!(create(&free_creator, 100, week + 1).unwrap_err(), Ok(SpotError::PlanLimitExceeded));
+    let free_event = create(&free_creator, 100, week).unwrap().unwrap();
+    let result = client.try_add_collection(
+        &free_creator,
+        &free_event,
+        &String::from_str(&e, "Speaker"),
+        &1u32,
+        &0u32,
+        &0u64,
+        &week,
+        &String::from_str(&e, "https://example.com/speaker.json"),
+        &String::from_str(&e, "https://example.com/speaker.png"),
+    );
+    assert_eq!(result.unwrap_err(), Ok(SpotError::PlanLimitExceeded));
+
+    // Paid plans consume one credit per badge of capacity
+    client.approve_creator(&admin, &paid_creator, &String::from_str(&e, "pro"), &Plan::Pro, &500u32);
+    let paid_event = create(&paid_creator, 300, u64::MAX).unwrap().unwrap();
+    assert_eq!(client.get_credits(&paid_creator), 200);
+    assert_eq!(create(&paid_creator, 201, u64::MAX).unwrap_err(), Ok(SpotError::InsufficientCredits));
+
+    // Unused capacity is refunded when the event closes
+    client.publish_event(&paid_creator, &paid_event);
+    client.airdrop(&paid_event, &paid_creator, &vec![&e, Address::generate(&e), Address::generate(&e)]);
+    client.close_event(&paid_creator, &paid_event);
+    assert_eq!(client.get_credits(&paid_creator), 498);
+
+    let result = client.try_approve_creator(&admin, &free_creator, &String::from_str(&e, "x"), &Plan::Free, &10u32);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidParameters));
+    assert_eq!(client.get_creator_approval(&paid_creator).unwrap().plan, Plan::Pro);
+}
+
+fn create_token<'a>(e: &Env, holder: &Address, amount: i128) -> token::Client<'a> {
+    let issuer = Address::generate(e);
+    let sac = e.register_stellar_asset_contract_v2(issuer);
+    token::StellarAssetClient::new(e, &sac.address()).mint(holder, &amount);
+    token::Client::new(e, &sac.address())
+}
+
+#[test]
+fn test_purchase_approval_with_token() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let creator = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let usdc = create_token(&e, &creator, 1_000);
+
+    let result = client.try_purchase_approval(&creator, &usdc.address, &Plan::Starter);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::PriceNotSet));
+
+    let price = ApprovalPrice { amount: 400, credits: 250 };
+    client.set_approval_price(&admin, &usdc.address, &Plan::Starter, &Some(price.clone()));
+    assert_eq!(client.get_approval_price(&usdc.address, &Plan::Starter), Some(price));
+
+    client.purchase_approval(&creator, &usdc.address, &Plan::Starter);
+    assert_eq!(usdc.balance(&creator), 600);
+    assert_eq!(usdc.balance(&client.address), 400);
+    assert_eq!(client.get_credits(&creator), 250);
+    let approval = client.get_creator_approval(&creator).unwrap();
+    assert_eq!(approval.plan, Plan::Starter);
+    assert_eq!(
+        approval.payment,
+        Some(CreatorPayment { token: usdc.address.clone(), amount: 400, credits: 250 })
+    );
+    create_test_event(&e, &client, &creator, 50);
+
+    // Approved creators cannot purchase over their approval
+    let result = client.try_purchase_approval(&creator, &usdc.address, &Plan::Starter);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::CreatorAlreadyApproved));
+
+    // Refunded revocation returns the unspent share of the payment and clears the credits
+    client.revoke_creator_approval(&admin, &creator, &true);
+    assert_eq!(usdc.balance(&creator), 920);
+    assert_eq!(client.get_credits(&creator), 0);
+    assert_eq!(client.get_creator_approval(&creator), None);
+
+    // Treasury withdrawals are admin-only
+    client.purchase_approval(&creator, &usdc.address, &Plan::Starter);
+    let result = client.try_withdraw_treasury(&creator, &usdc.address, &creator, &480);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+    client.withdraw_treasury(&admin, &usdc.address, &treasury, &480);
+    assert_eq!(usdc.balance(&treasury), 480);
+    assert_eq!(usdc.balance(&client.address), 0);
+
+    // Off-chain approvals have nothing to refund
+    let other = Address::generate(&e);
+    client.approve_creator(&admin, &other, &String::from_str(&e, "invoice-1"), &Plan::Free, &0u32);
+    let result = client.try_revoke_creator_approval(&admin, &other, &true);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidParameters));
+}
+
+#[test]
+fn test_reapproval_keeps_onchain_payment() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let creator = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let usdc = create_token(&e, &creator, 1_000);
+    let price = ApprovalPrice { amount: 400, credits: 250 };
+    client.set_approval_price(&admin, &usdc.address, &Plan::Pro, &Some(price));
+    client.purchase_approval(&creator, &usdc.address, &Plan::Pro);
+
+    // An off-chain approval on a lower plan neither erases the payment nor downgrades
+    client.approve_creator(&admin, &creator, &String::from_str(&e, "invoice-2"), &Plan::Free, &0u32);
+    let approval = client.get_creator_approval(&creator).unwrap();
+    assert_eq!(approval.payment_reference, String::from_str(&e, "invoice-2"));
+    assert_eq!(approval.plan, Plan::Pro);
+    assert_eq!(
+        approval.payment,
+        Some(CreatorPayment { token: usdc.address.clone(), amount: 400, credits: 250 })
+    );
+
+    // A higher plan upgrades the approval and the payment stays refundable
+    client.approve_creator(&admin, &creator, &String::from_str(&e, "invoice-3"), &Plan::Enterprise, &0u32);
+    assert_eq!(client.get_creator_approval(&creator).unwrap().plan, Plan::Enterprise);
+    client.revoke_creator_approval(&admin, &creator, &true);
+    assert_eq!(usdc.balance(&creator), 1_000);
+}
+
+#[test]
+fn test_donations_mint_donor_badges() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let donor = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let xlm = create_token(&e, &donor, 10_000);
+
+    let result = client.try_donate(&donor, &xlm.address, &100);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::DonationsDisabled));
+
+    // Donor event with a Bronze and a Gold tier
+    let event_id = create_test_event(&e, &client, &admin, 1);
+    let add_tier = |name: &str| {
+        client.add_collection(
+            &admin,
+            &event_id,
+            &String::from_str(&e, name),
+            &0u32,
+            &100u32,
+            &0u64,
+            &u64::MAX,
+            &String::from_str(&e, "https://example.com/donor.json"),
+            &String::from_str(&e, "https://example.com/donor.png"),
+        )
+    };
+    let bronze = add_tier("Donante Bronce");
+    let gold = add_tier("Donante Oro");
+    let config = DonationConfig {
+        event_id,
+        tiers: vec![
+            &e,
+            DonorTier { min_total: 100, collection_id: bronze },
+            DonorTier { min_total: 1_000, collection_id: gold },
+        ],
+    };
+    client.set_donation_config(&admin, &xlm.address, &Some(config));
+
+    // Below the first tier: no badge, but the total is tracked
+    assert_eq!(client.donate(&donor, &xlm.address, &50).len(), 0);
+    assert_eq!(client.donate(&donor, &xlm.address, &50).len(), 1);
+    assert!(client.has_claimed_collection(&event_id, &bronze, &donor));
+    assert_eq!(client.donate(&donor, &xlm.address, &500).len(), 0);
+
+    let badges = client.donate(&donor, &xlm.address, &400);
+    assert_eq!(badges.len(), 1);
+    assert_eq!(client.get_badge_collection(&badges.get(0).unwrap()), gold);
+    assert_eq!(client.get_donor_total(&donor, &xlm.address), 1_000);
+    assert_eq!(xlm.balance(&client.address), 1_000);
+
+    // Donations are withdrawn through the treasury
+    let project = Address::generate(&e);
+    client.withdraw_treasury(&admin, &xlm.address, &project, &1_000);
+    assert_eq!(xlm.balance(&project), 1_000);
+}
+
+#[test]
+fn test_donation_to_paused_donor_event_skips_badge() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let donor = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let xlm = create_token(&e, &donor, 1_000);
+
+    let event_id = create_test_event(&e, &client, &admin, 1);
+    let bronze = client.add_collection(
+        &admin,
+        &event_id,
+        &String::from_str(&e, "Donante Bronce"),
+        &0u32,
+        &100u32,
+        &0u64,
+        &u64::MAX,
+        &String::from_str(&e, "https://example.com/donor.json"),
+        &String::from_str(&e, "https://example.com/donor.png"),
+    );
+    let config = DonationConfig {
+        event_id,
+        tiers: vec![&e, DonorTier { min_total: 100, collection_id: bronze }],
+    };
+    client.set_donation_config(&admin, &xlm.address, &Some(config));
+
+    // The donation is accepted and counted, but no badge is minted while paused
+    client.pause_event(&admin, &event_id, &String::from_str(&e, "maintenance"));
+    assert_eq!(client.donate(&donor, &xlm.address, &100).len(), 0);
+    assert_eq!(client.get_donor_total(&donor, &xlm.address), 100);
+    assert_eq!(xlm.balance(&client.address), 100);
+    assert!(!client.has_claimed_collection(&event_id, &bronze, &donor));
+
+    // The badge follows with the next donation once the event is unpaused
+    client.unpause_event(&admin, &event_id, &String::from_str(&e, "back"));
+    assert_eq!(client.donate(&donor, &xlm.address, &1).len(), 1);
+    assert!(client.has_claimed_collection(&event_id, &bronze, &donor));
+}
+
+#[test]
+fn test_migrate_legacy_storage() {
+    use crate::migration::LegacyEventData;
+    use stellar_tokens::non_fungible::enumerable::Enumerable;
+
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let claimer = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    assert_eq!(client.version(), 2);
+
+    // Recreate the schema 1 layout: every record in instance storage, no schema version
+    let token_id = e.as_contract(&client.address, || {
+        let token_id = Enumerable::sequential_mint(&e, &claimer);
+        let instance = e.storage().instance();
+        instance.remove(&DataKey::SchemaVersion);
+        instance.set(&DataKey::EventCounter, &2u32);
+        for event_id in 1..=2u32 {
+            let legacy = LegacyEventData {
+                event_id,
+                creator: admin.clone(),
+                event_name: String::from_str(&e, "Legacy Event"),
+                event_date: 1735689600u64,
+                location: String::from_str(&e, "Test Location"),
+                description: String::from_str(&e, "Test Description"),
+                max_poaps: 10,
+                claim_start: 0,
+                claim_end: u64::MAX,
+                metadata_uri: String::from_str(&e, "https://example.com/metadata.json"),
+                image_url: String::from_str(&e, "https://example.com/image.png"),
+            };
+            instance.set(&DataKey::EventInfo(event_id), &legacy);
+            instance.set(&DataKey::EventMintedCount(event_id), &0u32);
+        }
+        instance.set(&DataKey::EventMintedCount(1), &1u32);
+        instance.set(&DataKey::EventTokenId(1, 0), &token_id);
+        instance.set(&DataKey::HasClaimed(1, claimer.clone()), &true);
+        instance.set(&DataKey::UserEventTokenId(1, claimer.clone()), &token_id);
+        token_id
+    });
+    assert_eq!(client.version(), 1);
+    assert_eq!(client.try_get_event(&1).unwrap_err(), Ok(SpotError::EventNotFound));
+
+    let result = client.try_migrate(&claimer, &10);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+
+    // First batch moves event 1 and its badge index only
+    assert!(!client.migrate(&admin, &2));
+    assert_eq!(client.get_event(&1).status, EventStatus::Published);
+    assert_eq!(client.get_event_poaps(&1), vec![&e, token_id]);
+    assert_eq!(client.try_get_event(&2).unwrap_err(), Ok(SpotError::EventNotFound));
+    assert_eq!(client.version(), 1);
+
+    assert!(client.migrate(&admin, &10));
+    assert_eq!(client.version(), 2);
+    assert_eq!(client.get_event(&2).event_name, String::from_str(&e, "Legacy Event"));
+    assert_eq!(client.get_badge_event(&token_id), 1);
+    assert!(client.migrate(&admin, &10));
+
+    // Address-keyed claim records are still honoured from the legacy layout
+    assert!(client.has_claimed(&1, &claimer));
+    assert_eq!(client.get_user_poap_for_event(&1, &claimer), token_id);
+    let result = client.try_claim(&1, &claimer);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::AlreadyClaimed));
+}
+
+#[test]
+fn test_fresh_contract_skips_legacy_claim_fallback() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let claimer = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let event_id = create_test_event(&e, &client, &admin, 10);
+
+    // Contracts deployed at schema 2 never read the schema 1 claim layout
+    e.as_contract(&client.address, || {
+        e.storage().instance().set(&DataKey::HasClaimed(event_id, claimer.clone()), &true);
+    });
+    assert!(!client.has_claimed(&event_id, &claimer));
+    client.claim(&event_id, &claimer);
+    assert!(client.has_claimed(&event_id, &claimer));
+}
+
+#[test]
+fn test_timelocked_admin_operations() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let other_admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let delay = client.timelock_delay();
+
+    // Granting an admin role only takes effect after the delay
+    let id = client.grant_admin_role(&other_admin, &admin);
+    assert_eq!(client.pending_operations().len(), 1);
+    let result = client.try_execute_operation(&admin, &id);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::OperationNotReady));
+    let result = client.try_approve_creator(
+        &other_admin, &other_admin, &String::from_str(&e, "x"), &Plan::Free, &0u32,
+    );
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+
+    e.ledger().with_mut(|li| li.timestamp += delay);
+    client.execute_operation(&admin, &id);
+    assert_eq!(client.pending_operations().len(), 0);
+    client.approve_creator(&other_admin, &other_admin, &String::from_str(&e, "x"), &Plan::Free, &0u32);
+
+    // Only the contract admin schedules upgrades; any admin can cancel them
+    let wasm_hash = BytesN::from_array(&e, &[0u8; 32]);
+    let result = client.try_upgrade(&other_admin, &wasm_hash);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+    let id = client.upgrade(&admin, &wasm_hash);
+    client.cancel_operation(&other_admin, &id);
+    e.ledger().with_mut(|li| li.timestamp += delay);
+    let result = client.try_execute_operation(&admin, &id);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::OperationNotFound));
+
+    // The delay itself is timelocked
+    let id = client.set_timelock_delay(&admin, &60);
+    assert_eq!(client.timelock_delay(), delay);
+    e.ledger().with_mut(|li| li.timestamp += delay);
+    client.execute_operation(&admin, &id);
+    assert_eq!(client.timelock_delay(), 60);
+}
+
+#[test]
+fn test_multisig_creator_approval() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let creator = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let (a1, a2, a3) = (Address::generate(&e), Address::generate(&e), Address::generate(&e));
+    let reference = String::from_str(&e, "invoice-789");
+
+    let result = client.try_set_approvers(&admin, &vec![&e, a1.clone(), a2.clone()], &3u32);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidParameters));
+    client.set_approvers(&admin, &vec![&e, a1.clone(), a2.clone(), a3.clone()], &2u32);
+    let result = client.try_set_approvers(&admin, &vec![&e, a1.clone()], &1u32);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+
+    // Admins no longer approve alone; signatures accumulate per proposal
+    let result = client.try_approve_creator(&admin, &creator, &reference, &Plan::Pro, &100u32);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+    assert!(!client.approve_creator(&a1, &creator, &reference, &Plan::Pro, &100u32));
+    let result = client.try_approve_creator(&a1, &creator, &reference, &Plan::Pro, &100u32);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::AlreadySigned));
+    let result = client.try_approve_creator(&a2, &creator, &reference, &Plan::Pro, &500u32);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidParameters));
+    assert!(client.get_creator_approval(&creator).is_none());
+
+    // A proposal signed with the wrong terms is cancelled by any approver and restarted
+    let other = Address::generate(&e);
+    let other_reference = String::from_str(&e, "invoice-790");
+    assert!(!client.approve_creator(&a1, &other, &other_reference, &Plan::Starter, &10u32));
+    let result = client.try_cancel_creator_proposal(&admin, &other, &other_reference);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+    client.cancel_creator_proposal(&a3, &other, &other_reference);
+    assert!(client.get_creator_proposal(&other, &other_reference).is_none());
+    let result = client.try_cancel_creator_proposal(&a3, &other, &other_reference);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::ProposalNotFound));
+    assert!(!client.approve_creator(&a1, &other, &other_reference, &Plan::Pro, &10u32));
+
+    assert!(client.approve_creator(&a2, &creator, &reference, &Plan::Pro, &100u32));
+    assert_eq!(client.get_credits(&creator), 100);
+    assert_eq!(client.get_creator_approval(&creator).unwrap().approved_by, a2);
+    assert!(client.get_creator_proposal(&creator, &reference).is_none());
+
+    // Approver changes go through the same threshold; removed signers stop counting
+    let a4 = Address::generate(&e);
+    assert!(!client.propose_approver_change(&a3, &ApproverChange::Add(a4.clone())));
+    assert!(!client.propose_approver_change(&a1, &ApproverChange::Remove(a3.clone())));
+    assert!(client.propose_approver_change(&a2, &ApproverChange::Remove(a3.clone())));
+    assert!(!client.propose_approver_change(&a1, &ApproverChange::Add(a4.clone())));
+    assert_eq!(
+        client.get_approver_proposal(&ApproverChange::Add(a4.clone())),
+        vec![&e, a3.clone(), a1.clone()]
+    );
+    assert!(client.propose_approver_change(&a2, &ApproverChange::Add(a4.clone())));
+    assert_eq!(
+        client.get_approvers(),
+        Some(ApproverSet {
+            approvers: vec![&e, a1.clone(), a2.clone(), a4.clone()],
+            threshold: 2,
+        })
+    );
+
+    let result = client.try_propose_approver_change(&a1, &ApproverChange::SetThreshold(4));
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidParameters));
+    let result = client.try_approve_creator(&a3, &creator, &reference, &Plan::Pro, &100u32);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+}
+
+#[test]
+fn test_pause_contract_and_event() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    let event_id = create_test_event(&e, &client, &admin, 10);
+    let other_event = create_test_event(&e, &client, &admin, 10);
+    let token_id = client.claim(&event_id, &user);
+    let reason = String::from_str(&e, "codes leaked");
+
+    // Per-event pause only freezes that event
+    let result = client.try_pause_event(&user, &event_id, &reason);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+    client.pause_event(&admin, &event_id, &reason);
+    assert!(client.is_event_paused(&event_id));
+    assert_eq!(client.get_event_pause(&event_id).unwrap().reason, reason);
+    let result = client.try_claim(&event_id, &Address::generate(&e));
+    assert_eq!(result.unwrap_err(), Ok(SpotError::EventPaused));
+    assert!(client.try_transfer(&user, &Address::generate(&e), &token_id).is_err());
+    client.claim(&other_event, &Address::generate(&e));
+
+    client.unpause_event(&admin, &event_id, &String::from_str(&e, "codes rotated"));
+    assert!(!client.is_event_paused(&event_id));
+    client.claim(&event_id, &Address::generate(&e));
+
+    // Contract-wide pause blocks claims, transfers and event creation everywhere
+    client.pause(&admin, &String::from_str(&e, "incident"));
+    assert_eq!(client.paused().unwrap().paused_by, admin);
+    assert!(client.is_event_paused(&other_event));
+    let result = client.try_claim(&other_event, &Address::generate(&e));
+    assert_eq!(result.unwrap_err(), Ok(SpotError::ContractPaused));
+    assert!(client.try_transfer(&user, &Address::generate(&e), &token_id).is_err());
+    let result = client.try_create_event(
+        &admin,
+        &String::from_str(&e, "Paused Event"),
+        &1735689600u64,
+        &String::from_str(&e, "Lima"),
+        &String::from_str(&e, "Description"),
+        &10u32,
+        &0u64,
+        &u64::MAX,
+        &String::from_str(&e, "https://example.com/metadata.json"),
+        &String::from_str(&e, "https://example.com/image.png"),
+    );
+    assert_eq!(result.unwrap_err(), Ok(SpotError::ContractPaused));
+
+    let result = client.try_unpause(&admin, &String::from_str(&e, ""));
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidParameters));
+    client.unpause(&admin, &String::from_str(&e, "resolved"));
+    assert!(client.paused().is_none());
+    let recipient = Address::generate(&e);
+    client.transfer(&user, &recipient, &token_id);
+    assert_eq!(client.owner_of(&token_id), recipient);
+}
+
+#[test]
+fn test_two_step_admin_transfer() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let new_admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    // Direct access control transfers are disabled in favour of the timelocked flow
+    assert!(client.try_transfer_admin_role(&new_admin, &1000u32).is_err());
+
+    let id = client.set_admin(&admin, &new_admin);
+    e.ledger().with_mut(|li| li.timestamp += client.timelock_delay());
+    client.execute_operation(&admin, &id);
+    assert_eq!(client.admin(), admin);
+
+    client.accept_admin_transfer();
+    assert_eq!(client.admin(), new_admin);
+    let result = client.try_set_admin(&admin, &Address::generate(&e));
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+}
+
+#[test]
+fn test_event_owner_transfer_and_co_organizers() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let co_organizer = Address::generate(&e);
+    let new_owner = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    client.approve_creator(&admin, &owner, &String::from_str(&e, "invoice-1"), &Plan::Pro, &100u32);
+    let event_id = create_test_event(&e, &client, &owner, 10);
+
+    // Co-organizers manage the event but cannot change co-organizers or ownership
+    client.grant_event_role(&owner, &event_id, &co_organizer, &EventRole::Organizer);
+    let minter = Address::generate(&e);
+    client.grant_event_role(&co_organizer, &event_id, &minter, &EventRole::Minter);
+    let result = client.try_grant_event_role(&co_organizer, &event_id, &minter, &EventRole::Organizer);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+    let result = client.try_propose_event_owner(&co_organizer, &event_id, &co_organizer, &100u32);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+
+    // Proposals expire, and only the proposed address can accept
+    let live_until = e.ledger().sequence() + 10;
+    client.propose_event_owner(&owner, &event_id, &new_owner, &live_until);
+    let result = client.try_accept_event_owner(&co_organizer, &event_id);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+    e.ledger().with_mut(|li| li.sequence_number = live_until + 1);
+    let result = client.try_accept_event_owner(&new_owner, &event_id);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::TransferExpired));
+
+    let live_until = e.ledger().sequence() + 10;
+    client.propose_event_owner(&owner, &event_id, &new_owner, &live_until);
+    client.accept_event_owner(&new_owner, &event_id);
+    assert_eq!(client.get_event(&event_id).creator, new_owner);
+    assert!(client.get_pending_event_owner(&event_id).is_none());
+
+    // The previous owner lost their rights; the co-organizer kept theirs
+    let result = client.try_cancel_event(&owner, &event_id);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::Unauthorized));
+
+    // Credits keep flowing to and from the account that paid for the event
+    client.set_event_capacity(&new_owner, &event_id, &20u32);
+    assert_eq!(client.get_credits(&owner), 80);
+    client.close_event(&co_organizer, &event_id);
+    assert_eq!(client.get_credits(&owner), 100);
+    assert_eq!(client.get_credits(&new_owner), 0);
+}
+
+#[test]
+fn test_guarded_capacity_and_claim_window_edits() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let creator = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    client.approve_creator(&admin, &creator, &String::from_str(&e, "invoice-2"), &Plan::Pro, &20u32);
+    let event_id = client.create_event(
+        &creator,
+        &String::from_str(&e, "Workshop"),
+        &1735689600u64,
+        &String::from_str(&e, "Montevideo"),
+        &String::from_str(&e, "Description"),
+        &5u32,
+        &100u64,
+        &200u64,
+        &String::from_str(&e, "https://example.com/metadata.json"),
+        &String::from_str(&e, "https://example.com/image.png"),
+    );
+    client.publish_event(&creator, &event_id);
+
+    // Before claiming starts, a published event cannot open later nor in the past
+    e.ledger().with_mut(|li| li.timestamp = 50);
+    let result = client.try_set_claim_window(&creator, &event_id, &120u64, &200u64);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidParameters));
+    let result = client.try_set_claim_window(&creator, &event_id, &40u64, &200u64);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidParameters));
+
+    e.ledger().with_mut(|li| li.timestamp = 150);
+    client.claim(&event_id, &Address::generate(&e));
+    client.claim(&event_id, &Address::generate(&e));
+
+    // Capacity rises against credits and falls down to the minted count
+    client.set_event_capacity(&creator, &event_id, &10u32);
+    assert_eq!(client.get_credits(&creator), 10);
+    let result = client.try_set_event_capacity(&creator, &event_id, &100u32);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InsufficientCredits));
+    let result = client.try_set_event_capacity(&creator, &event_id, &1u32);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidParameters));
+    client.set_event_capacity(&creator, &event_id, &2u32);
+    assert_eq!(client.get_credits(&creator), 18);
+
+    // Claiming has started: the start is fixed and the end can only move later
+    let result = client.try_set_claim_window(&creator, &event_id, &120u64, &300u64);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidParameters));
+    let result = client.try_set_claim_window(&creator, &event_id, &100u64, &180u64);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidParameters));
+
+    // An ended window reopens within the grace period only
+    e.ledger().with_mut(|li| li.timestamp = 250);
+    client.set_claim_window(&creator, &event_id, &100u64, &400u64);
+    assert_eq!(client.get_event(&event_id).claim_end, 400);
+    e.ledger().with_mut(|li| li.timestamp = 400 + REOPEN_GRACE_PERIOD + 1);
+    let result = client.try_set_claim_window(&creator, &event_id, &100u64, &u64::MAX);
+    assert_eq!(result.unwrap_err(), Ok(SpotError::ClaimPeriodEnded));
+
+    let changes = client.get_event_changes(&event_id);
+    assert_eq!(changes.len(), 3);
+    let last = changes.get(2).unwrap();
+    assert_eq!(last.field, EventField::ClaimEnd);
+    assert_eq!((last.old_value, last.new_value), (200, 400));
+    assert_eq!(last.operator, creator);
+
+    // The history keeps the latest entries without blocking further edits
+    for _ in 0..MAX_EVENT_CHANGES {
+        client.set_event_capacity(&creator, &event_id, &3u32);
+        client.set_event_capacity(&creator, &event_id, &2u32);
+    }
+    let changes = client.get_event_changes(&event_id);
+    assert_eq!(changes.len(), MAX_EVENT_CHANGES);
+    let last = changes.last().unwrap();
+    assert_eq!((last.old_value, last.new_value), (3, 2));
+}
+
+#[test]
+fn test_free_event_reschedules_full_claim_window() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let creator = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    client.approve_creator(&admin, &creator, &String::from_str(&e, "invoice-4"), &Plan::Free, &0u32);
+    let week = 7 * 24 * 60 * 60;
+    let event_id = client.create_event(
+        &creator,
+        &String::from_str(&e, "Meetup"),
+        &1735689600u64,
+        &String::from_str(&e, "Lima"),
+        &String::from_str(&e, "Description"),
+        &10u32,
+        &100u64,
+        &(100 + week),
+        &String::from_str(&e, "https://example.com/metadata.json"),
+        &String::from_str(&e, "https://example.com/image.png"),
+    );
+    client.publish_event(&creator, &event_id);
+
+    // Extending a full window breaks the free plan's limit
+    let result = client.try_set_claim_window(&creator, &event_id, &100u64, &(200 + week));
+    assert_eq!(result.unwrap_err(), Ok(SpotError::PlanLimitExceeded));
+
+    // Once it ends, the window is reopened as a new week-long window
+    let now = 100 + week + 10;
+    e.ledger().with_mut(|li| li.timestamp = now);
+    let result = client.try_set_claim_window(&creator, &event_id, &100u64, &(now + week));
+    assert_eq!(result.unwrap_err(), Ok(SpotError::PlanLimitExceeded));
+    let result = client.try_set_claim_window(&creator, &event_id, &50u64, &(now + 10));
+    assert_eq!(result.unwrap_err(), Ok(SpotError::InvalidParameters));
+    client.set_claim_window(&creator, &event_id, &now, &(now + week));
+
+    let event = client.get_event(&event_id);
+    assert_eq!((event.claim_start, event.claim_end), (now, now + week));
+    client.claim(&event_id, &Address::generate(&e));
+}
@@ -3,7 +3,9 @@
 //! This contract manages SPOT (Stellar Proof of Togetherness) NFTs for a specific event.
 //! It handles minting, burning, role-based access control, and claim period validation.
 
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, String};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error, symbol_short, Address, Env, String,
+};
 use stellar_access::access_control::{self as access_control, AccessControl};
 use stellar_macros::{default_impl, only_role};
 use stellar_tokens::non_fungible::{
@@ -13,6 +15,10 @@ use stellar_tokens::non_fungible::{
 };
 
 use crate::error::SpotEventError;
+use crate::events::{
+    AdminGranted, MinterGranted, MinterRevoked, OwnerChanged, PauseChanged, SpotMinted,
+    UnclaimedBurned,
+};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -29,6 +35,16 @@ pub enum DataKey {
     MetadataUri,        // URI pointing to event metadata JSON
     ImageUrl,           // URL of the event image
     HasMinted(Address), // Track if an address has already minted (prevents duplicates)
+    Paused,             // Emergency pause of minting and transfers (PauseInfo)
+}
+
+/// Emergency pause and why it was set
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseInfo {
+    pub reason: String,
+    pub paused_by: Address,
+    pub paused_at: u64,
 }
 
 #[contract]
@@ -105,7 +121,7 @@ impl SpotEvent {
             .ok_or(SpotEventError::Unauthorized)
     }
 
-    /// Mint a SPOT NFT to an address
+    /// Mint a SPOT NFT to an address (requires the owner's authorization)
     /// 
     /// # Arguments
     /// * `to` - Address that will receive the SPOT NFT
@@ -113,52 +129,75 @@ impl SpotEvent {
     /// # Returns
     /// The token ID of the minted NFT
     pub fn mint(e: &Env, to: Address) -> Result<u32, SpotEventError> {
-        // Check if claim period is active
-        let current_time = e.ledger().timestamp();
-        let claim_start: u64 = e.storage().instance().get(&DataKey::ClaimStart)
-            .expect("claim_start should be set");
-        let claim_end: u64 = e.storage().instance().get(&DataKey::ClaimEnd)
-            .expect("claim_end should be set");
+        Self::owner(e)?.require_auth();
+        Self::mint_to(e, to)
+    }
 
-        if current_time < claim_start {
-            return Err(SpotEventError::ClaimPeriodNotStarted);
+    /// Mint a SPOT NFT (only authorized roles can call this)
+    ///
+    /// # Arguments
+    /// * `to` - Address that will receive the SPOT NFT
+    /// * `operator` - Owner, admin or minter; must authorize the call
+    pub fn mint_authorized(e: &Env, to: Address, operator: Address) -> Result<u32, SpotEventError> {
+        operator.require_auth();
+        Self::require_minter_or_admin(e, &operator)?;
+
+        Self::mint_to(e, to)
+    }
+
+    /// Emergency pause of minting and transfers (only owner or admin)
+    ///
+    /// # Arguments
+    /// * `operator` - Owner or admin address
+    /// * `reason` - Why the event is paused, kept for the audit trail
+    pub fn pause(e: &Env, operator: Address, reason: String) -> Result<(), SpotEventError> {
+        operator.require_auth();
+        Self::require_owner_or_admin(e, &operator)?;
+        if reason.is_empty() {
+            return Err(SpotEventError::InvalidParameters);
         }
-        if current_time > claim_end {
-            return Err(SpotEventError::ClaimPeriodEnded);
+        if Self::paused(e).is_some() {
+            return Err(SpotEventError::Paused);
         }
 
-        // Check if address has already minted (prevent duplicates)
-        if e.storage().instance().has(&DataKey::HasMinted(to.clone())) {
-            return Err(SpotEventError::AlreadyMinted);
+        let pause = PauseInfo {
+            reason: reason.clone(),
+            paused_by: operator.clone(),
+            paused_at: e.ledger().timestamp(),
+        };
+        e.storage().instance().set(&DataKey::Paused, &pause);
+
+        PauseChanged {
+            paused: true,
+            operator,
+            reason,
         }
+        .publish(e);
+        Ok(())
+    }
 
-        // Check if limit is exceeded
-        let minted: u32 = e.storage().instance().get(&DataKey::MintedCount)
-            .expect("minted_count should be set");
-        let max_nfts: u32 = e.storage().instance().get(&DataKey::MaxNfts)
-            .expect("max_nfts should be set");
-
-        if minted >= max_nfts {
-            return Err(SpotEventError::LimitExceeded);
+    /// Lift the pause (only owner or admin)
+    pub fn unpause(e: &Env, operator: Address, reason: String) -> Result<(), SpotEventError> {
+        operator.require_auth();
+        Self::require_owner_or_admin(e, &operator)?;
+        if reason.is_empty() || Self::paused(e).is_none() {
+            return Err(SpotEventError::InvalidParameters);
         }
 
-        // Mint the NFT
-        let token_id = Enumerable::sequential_mint(e, &to);
-
-        // Update counters and tracking
-        e.storage().instance().set(&DataKey::MintedCount, &(minted + 1));
-        e.storage().instance().set(&DataKey::HasMinted(to.clone()), &true);
+        e.storage().instance().remove(&DataKey::Paused);
 
-        Ok(token_id)
+        PauseChanged {
+            paused: false,
+            operator,
+            reason,
+        }
+        .publish(e);
+        Ok(())
     }
 
-    /// Mint a SPOT NFT (only authorized roles can call this)
-    /// This is a wrapper for mint() that requires authorization
-    pub fn mint_authorized(e: &Env, to: Address, operator: Address) -> Result<u32, SpotEventError> {
-        // Verify operator has minter role or is owner/admin
-        Self::require_minter_or_admin(e, &operator)?;
-        
-        Self::mint(e, to)
+    /// Current pause, if set
+    pub fn paused(e: &Env) -> Option<PauseInfo> {
+        e.storage().instance().get(&DataKey::Paused)
     }
 
     /// Check if an address has minted
@@ -185,6 +224,7 @@ impl SpotEvent {
     /// Burn unclaimed NFTs (only owner or admin can call)
     /// This burns all NFTs that haven't been minted after the claim period ends
     pub fn burn_unclaimed(e: &Env, operator: Address) -> Result<u32, SpotEventError> {
+        operator.require_auth();
         Self::require_owner_or_admin(e, &operator)?;
 
         let current_time = e.ledger().timestamp();
@@ -205,6 +245,8 @@ impl SpotEvent {
         // Update max_nfts to current minted count (effectively burning unclaimed)
         e.storage().instance().set(&DataKey::MaxNfts, &minted);
 
+        UnclaimedBurned { operator, unclaimed }.publish(e);
+
         Ok(unclaimed)
     }
 
@@ -212,6 +254,12 @@ impl SpotEvent {
     pub fn grant_minter_role(e: &Env, minter: Address, operator: Address) -> Result<(), SpotEventError> {
         Self::require_owner_or_admin(e, &operator)?;
         access_control::grant_role(e, &operator, &minter, &symbol_short!("minter"));
+
+        MinterGranted {
+            account: minter,
+            operator,
+        }
+        .publish(e);
         Ok(())
     }
 
@@ -219,6 +267,12 @@ impl SpotEvent {
     pub fn revoke_minter_role(e: &Env, minter: Address, operator: Address) -> Result<(), SpotEventError> {
         Self::require_owner_or_admin(e, &operator)?;
         access_control::revoke_role(e, &operator, &minter, &symbol_short!("minter"));
+
+        MinterRevoked {
+            account: minter,
+            operator,
+        }
+        .publish(e);
         Ok(())
     }
 
@@ -227,9 +281,62 @@ impl SpotEvent {
         let owner = Self::owner(e)?;
         owner.require_auth();
         access_control::grant_role(e, &operator, &admin, &symbol_short!("admin"));
+
+        AdminGranted {
+            account: admin,
+            operator,
+        }
+        .publish(e);
         Ok(())
     }
 
+    /// Shared by `mint` and `mint_authorized` once the caller is authorized
+    fn mint_to(e: &Env, to: Address) -> Result<u32, SpotEventError> {
+        if Self::paused(e).is_some() {
+            return Err(SpotEventError::Paused);
+        }
+
+        // Check if claim period is active
+        let current_time = e.ledger().timestamp();
+        let claim_start: u64 = e.storage().instance().get(&DataKey::ClaimStart)
+            .expect("claim_start should be set");
+        let claim_end: u64 = e.storage().instance().get(&DataKey::ClaimEnd)
+            .expect("claim_end should be set");
+
+        if current_time < claim_start {
+            return Err(SpotEventError::ClaimPeriodNotStarted);
+        }
+        if current_time > claim_end {
+            return Err(SpotEventError::ClaimPeriodEnded);
+        }
+
+        // Check if address has already minted (prevent duplicates)
+        if e.storage().instance().has(&DataKey::HasMinted(to.clone())) {
+            return Err(SpotEventError::AlreadyMinted);
+        }
+
+        // Check if limit is exceeded
+        let minted: u32 = e.storage().instance().get(&DataKey::MintedCount)
+            .expect("minted_count should be set");
+        let max_nfts: u32 = e.storage().instance().get(&DataKey::MaxNfts)
+            .expect("max_nfts should be set");
+
+        if minted >= max_nfts {
+            return Err(SpotEventError::LimitExceeded);
+        }
+
+        // Mint the NFT
+        let token_id = Enumerable::sequential_mint(e, &to);
+
+        // Update counters and tracking
+        e.storage().instance().set(&DataKey::MintedCount, &(minted + 1));
+        e.storage().instance().set(&DataKey::HasMinted(to.clone()), &true);
+
+        SpotMinted { to, token_id }.publish(e);
+
+        Ok(token_id)
+    }
+
     // Helper functions for role checking
     fn require_owner_or_admin(e: &Env, address: &Address) -> Result<(), SpotEventError> {
         let owner = Self::owner(e)?;
@@ -266,6 +373,20 @@ impl SpotEvent {
 #[contractimpl]
 impl NonFungibleToken for SpotEvent {
     type ContractType = Enumerable;
+
+    fn transfer(e: &Env, from: Address, to: Address, token_id: u32) {
+        if SpotEvent::paused(e).is_some() {
+            panic_with_error!(e, SpotEventError::Paused);
+        }
+        Enumerable::transfer(e, &from, &to, token_id);
+    }
+
+    fn transfer_from(e: &Env, spender: Address, from: Address, to: Address, token_id: u32) {
+        if SpotEvent::paused(e).is_some() {
+            panic_with_error!(e, SpotEventError::Paused);
+        }
+        Enumerable::transfer_from(e, &spender, &from, &to, token_id);
+    }
 }
 
 #[default_impl]
@@ -278,5 +399,22 @@ impl NonFungibleBurnable for SpotEvent {}
 
 #[default_impl]
 #[contractimpl]
-impl AccessControl for SpotEvent {}
+impl AccessControl for SpotEvent {
+    /// Complete a transfer started by the owner with `transfer_admin_role` (which sets
+    /// its expiry). The event owner follows the contract admin.
+    fn accept_admin_transfer(e: &Env) {
+        let previous_owner: Address = e.storage().instance().get(&DataKey::Owner)
+            .expect("owner should be set");
+        access_control::accept_admin_transfer(e);
+
+        let new_owner = access_control::get_admin(e).expect("admin should be set");
+        e.storage().instance().set(&DataKey::Owner, &new_owner);
+
+        OwnerChanged {
+            previous_owner,
+            new_owner,
+        }
+        .publish(e);
+    }
+}
 
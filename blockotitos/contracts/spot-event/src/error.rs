@@ -18,6 +18,8 @@ pub enum SpotEventError {
     InvalidParameters = 6,
     /// Role not found: Role does not exist
     RoleNotFound = 7,
+    /// Paused: Minting and transfers are paused
+    Paused = 8,
 }
 
 impl SpotEventError {
@@ -30,6 +32,7 @@ impl SpotEventError {
             SpotEventError::ClaimPeriodNotStarted => symbol_short!("NOT_START"),
             SpotEventError::InvalidParameters => symbol_short!("INV_PARAM"),
             SpotEventError::RoleNotFound => symbol_short!("NO_ROLE"),
+            SpotEventError::Paused => symbol_short!("PAUSED"),
         }
     }
 }
@@ -0,0 +1,67 @@
+//! SPOT Event contract events
+//!
+//! Published on every state change of the event contract. Topics are fixed: the event
+//! name first, then the indexed fields in declaration order.
+
+use soroban_sdk::{contractevent, Address, String};
+
+/// Published when a SPOT NFT is minted
+#[contractevent(topics = ["spot_minted"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpotMinted {
+    #[topic]
+    pub to: Address,
+    pub token_id: u32,
+}
+
+/// Published when the unclaimed supply is burned after the claim period
+#[contractevent(topics = ["unclaimed_burned"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnclaimedBurned {
+    pub operator: Address,
+    pub unclaimed: u32,
+}
+
+/// Published when the minter role is granted
+#[contractevent(topics = ["minter_granted"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MinterGranted {
+    #[topic]
+    pub account: Address,
+    pub operator: Address,
+}
+
+/// Published when the minter role is revoked
+#[contractevent(topics = ["minter_revoked"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MinterRevoked {
+    #[topic]
+    pub account: Address,
+    pub operator: Address,
+}
+
+/// Published when the event is paused or unpaused
+#[contractevent(topics = ["pause_changed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseChanged {
+    pub paused: bool,
+    pub operator: Address,
+    pub reason: String,
+}
+
+/// Published when a two-step admin transfer completes and the event changes owner
+#[contractevent(topics = ["owner_changed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnerChanged {
+    pub previous_owner: Address,
+    pub new_owner: Address,
+}
+
+/// Published when the admin role is granted
+#[contractevent(topics = ["admin_granted"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminGranted {
+    #[topic]
+    pub account: Address,
+    pub operator: Address,
+}
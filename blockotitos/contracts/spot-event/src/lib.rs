@@ -3,7 +3,9 @@ use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String};
 
 mod contract;
 mod error;
+mod events;
 
+pub use contract::PauseInfo;
 pub use contract::SpotEvent;
 use error::SpotEventError;
 
@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, String};
 
 #[test]
 fn test_constructor() {
@@ -43,89 +43,178 @@ fn test_constructor() {
     assert_eq!(minted, 0);
 }
 
+fn create_client<'a>(e: &'a Env, owner: &Address, max_nfts: u32) -> contract::SpotEventClient<'a> {
+    let address = e.register(
+        SpotEvent,
+        (
+            owner.clone(),
+            String::from_str(e, "Test Event"),
+            1735689600u64,
+            String::from_str(e, "Test Location"),
+            String::from_str(e, "Test Description"),
+            max_nfts,
+            0u64,
+            u64::MAX,
+            String::from_str(e, "https://example.com/metadata.json"),
+            String::from_str(e, "https://example.com/image.png"),
+        ),
+    );
+    contract::SpotEventClient::new(e, &address)
+}
+
 #[test]
 fn test_mint() {
     let e = Env::default();
+    e.mock_all_auths();
     let owner = Address::generate(&e);
     let recipient = Address::generate(&e);
-
-    let event_name = String::from_str(&e, "Test Event");
-    let event_date = 1735689600u64;
-    let location = String::from_str(&e, "Test Location");
-    let description = String::from_str(&e, "Test Description");
-    let max_nfts = 10u32;
-    let claim_start = 0u64; // Start immediately for testing
-    let claim_end = u64::MAX; // Never end for testing
-    let metadata_uri = String::from_str(&e, "https://example.com/metadata.json");
-    let image_url = String::from_str(&e, "https://example.com/image.png");
-
-    SpotEvent::__constructor(
-        &e,
-        owner.clone(),
-        event_name,
-        event_date,
-        location,
-        description,
-        max_nfts,
-        claim_start,
-        claim_end,
-        metadata_uri,
-        image_url,
-    );
+    let client = create_client(&e, &owner, 10);
 
     // Test mint
-    let token_id = SpotEvent::mint(&e, recipient.clone()).unwrap();
+    let token_id = client.mint(&recipient);
     assert_eq!(token_id, 0);
+    assert_eq!(e.auths()[0].0, owner);
 
     // Check has_minted
-    assert!(SpotEvent::has_minted(&e, recipient.clone()));
-    assert!(!SpotEvent::has_minted(&e, Address::generate(&e)));
+    assert!(client.has_minted(&recipient));
+    assert!(!client.has_minted(&Address::generate(&e)));
 
     // Test duplicate mint should fail
-    let result = SpotEvent::mint(&e, recipient.clone());
-    assert!(result.is_err());
-    assert_eq!(result.unwrap_err(), SpotEventError::AlreadyMinted);
+    let result = client.try_mint(&recipient);
+    assert_eq!(result.unwrap_err(), Ok(SpotEventError::AlreadyMinted));
 }
 
 #[test]
-fn test_limit_exceeded() {
+fn test_mint_requires_operator_auth() {
     let e = Env::default();
     let owner = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let client = create_client(&e, &owner, 10);
 
-    let event_name = String::from_str(&e, "Test Event");
-    let event_date = 1735689600u64;
-    let location = String::from_str(&e, "Test Location");
-    let description = String::from_str(&e, "Test Description");
-    let max_nfts = 2u32; // Limit to 2 NFTs
-    let claim_start = 0u64;
-    let claim_end = u64::MAX;
-    let metadata_uri = String::from_str(&e, "https://example.com/metadata.json");
-    let image_url = String::from_str(&e, "https://example.com/image.png");
+    // Nobody authorized the calls
+    assert!(client.try_mint(&Address::generate(&e)).is_err());
+    assert!(client.try_mint_authorized(&Address::generate(&e), &owner).is_err());
 
-    SpotEvent::__constructor(
-        &e,
-        owner.clone(),
-        event_name,
-        event_date,
-        location,
-        description,
-        max_nfts,
-        claim_start,
-        claim_end,
-        metadata_uri,
-        image_url,
-    );
+    e.mock_all_auths();
+    client.grant_minter_role(&minter, &owner);
+    client.mint_authorized(&Address::generate(&e), &minter);
+    assert_eq!(e.auths()[0].0, minter);
+
+    let result = client.try_mint_authorized(&Address::generate(&e), &Address::generate(&e));
+    assert_eq!(result.unwrap_err(), Ok(SpotEventError::Unauthorized));
+    assert_eq!(client.get_event_info().5, 1);
+}
+
+#[test]
+fn test_limit_exceeded() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let client = create_client(&e, &owner, 2); // Limit to 2 NFTs
 
     // Mint 2 NFTs
-    let recipient1 = Address::generate(&e);
-    let recipient2 = Address::generate(&e);
-    SpotEvent::mint(&e, recipient1).unwrap();
-    SpotEvent::mint(&e, recipient2).unwrap();
+    client.mint(&Address::generate(&e));
+    client.mint(&Address::generate(&e));
 
     // Third mint should fail
-    let recipient3 = Address::generate(&e);
-    let result = SpotEvent::mint(&e, recipient3);
-    assert!(result.is_err());
-    assert_eq!(result.unwrap_err(), SpotEventError::LimitExceeded);
+    let result = client.try_mint(&Address::generate(&e));
+    assert_eq!(result.unwrap_err(), Ok(SpotEventError::LimitExceeded));
+}
+
+#[test]
+fn test_pause_blocks_minting() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let address = e.register(
+        SpotEvent,
+        (
+            owner.clone(),
+            String::from_str(&e, "Test Event"),
+            1735689600u64,
+            String::from_str(&e, "Test Location"),
+            String::from_str(&e, "Test Description"),
+            10u32,
+            0u64,
+            u64::MAX,
+            String::from_str(&e, "https://example.com/metadata.json"),
+            String::from_str(&e, "https://example.com/image.png"),
+        ),
+    );
+    let client = contract::SpotEventClient::new(&e, &address);
+    let holder = Address::generate(&e);
+    let token_id = client.mint(&holder);
+
+    let result = client.try_pause(&Address::generate(&e), &String::from_str(&e, "codes leaked"));
+    assert_eq!(result.unwrap_err(), Ok(SpotEventError::Unauthorized));
+    client.pause(&owner, &String::from_str(&e, "codes leaked"));
+    assert_eq!(client.paused().unwrap().paused_by, owner);
+
+    let result = client.try_mint(&Address::generate(&e));
+    assert_eq!(result.unwrap_err(), Ok(SpotEventError::Paused));
+    assert!(client.try_transfer(&holder, &Address::generate(&e), &token_id).is_err());
+
+    client.unpause(&owner, &String::from_str(&e, "codes rotated"));
+    assert!(client.paused().is_none());
+    client.mint(&Address::generate(&e));
+}
+
+#[test]
+fn test_burn_unclaimed_requires_operator_auth() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let address = e.register(
+        SpotEvent,
+        (
+            owner.clone(),
+            String::from_str(&e, "Test Event"),
+            1735689600u64,
+            String::from_str(&e, "Test Location"),
+            String::from_str(&e, "Test Description"),
+            10u32,
+            0u64,
+            100u64,
+            String::from_str(&e, "https://example.com/metadata.json"),
+            String::from_str(&e, "https://example.com/image.png"),
+        ),
+    );
+    let client = contract::SpotEventClient::new(&e, &address);
+    client.mint(&Address::generate(&e));
+    e.ledger().with_mut(|li| li.timestamp = 200);
+
+    assert_eq!(client.burn_unclaimed(&owner), 9);
+    assert_eq!(e.auths()[0].0, owner);
 }
 
+#[test]
+fn test_two_step_owner_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let owner = Address::generate(&e);
+    let address = e.register(
+        SpotEvent,
+        (
+            owner.clone(),
+            String::from_str(&e, "Test Event"),
+            1735689600u64,
+            String::from_str(&e, "Test Location"),
+            String::from_str(&e, "Test Description"),
+            10u32,
+            0u64,
+            u64::MAX,
+            String::from_str(&e, "https://example.com/metadata.json"),
+            String::from_str(&e, "https://example.com/image.png"),
+        ),
+    );
+    let client = contract::SpotEventClient::new(&e, &address);
+    let new_owner = Address::generate(&e);
+
+    client.transfer_admin_role(&new_owner, &1000u32);
+    assert_eq!(client.owner(), owner);
+
+    client.accept_admin_transfer();
+    assert_eq!(client.owner(), new_owner);
+    assert_eq!(client.get_admin(), Some(new_owner.clone()));
+    client.pause(&new_owner, &String::from_str(&e, "handover check"));
+}
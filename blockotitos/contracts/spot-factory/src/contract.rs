@@ -1,26 +1,44 @@
 use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env, String, Vec};
+use spot_timelock::{self as timelock, Operation, TimelockAction, ACCEPT_WINDOW_LEDGERS};
 
 use crate::error::SpotFactoryError;
+use crate::events::{AdminChanged, AdminTransferProposed, EventDeployed, EventWasmUpdated};
+use crate::storage;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
     Admin,
-    Events,             // Lista de event IDs (stored as Vec<String>)
-    EventInfo(String),  // Info de un evento específico
-    EventCount,         // Contador de eventos
+    EventInfo(u32),                // Info de un evento específico
+    EventCount,                    // Contador de eventos (último ID asignado)
+    EventWasmHash,                 // WASM hash del contrato spot-event a desplegar
+    CreatorEventCount(Address),    // Número de eventos creados por un creador
+    CreatorEvent(Address, u32),    // creador + índice -> event ID
+    EventByContract(Address),      // Dirección del contrato -> event ID
+    PendingAdmin,                  // Admin propuesto, pendiente de aceptación
 }
 
+/// Maximum number of entries returned by a single paginated query
+const MAX_PAGE_SIZE: u32 = 50;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EventInfo {
-    pub id: String,
+    pub id: u32,
     pub contract_address: Address,
     pub creator: Address,
     pub name: String,
     pub created_at: u64,
 }
 
+/// Admin transfer awaiting acceptance by `account`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingAdmin {
+    pub account: Address,
+    pub live_until_ledger: u32,
+}
+
 #[contract]
 pub struct SpotFactory;
 
@@ -31,9 +49,7 @@ impl SpotFactory {
         admin.require_auth();
         e.storage().instance().set(&DataKey::Admin, &admin);
         
-        // Initialize empty events list and counter
-        let events: Vec<String> = Vec::new(&e);
-        e.storage().instance().set(&DataKey::Events, &events);
+        // Initialize event counter
         e.storage().instance().set(&DataKey::EventCount, &0u32);
     }
 
@@ -45,23 +61,144 @@ impl SpotFactory {
             .ok_or(SpotFactoryError::Unauthorized)
     }
 
-    /// Update admin (only current admin can update)
-    pub fn set_admin(e: &Env, new_admin: Address) -> Result<(), SpotFactoryError> {
+    /// Schedule an admin change (only current admin can schedule).
+    /// Once the timelock delay has passed, `execute_operation` offers the role to
+    /// `new_admin`, who has `ACCEPT_WINDOW_LEDGERS` to call `accept_admin`.
+    ///
+    /// # Returns
+    /// The ID of the scheduled operation
+    pub fn set_admin(e: &Env, new_admin: Address) -> Result<u64, SpotFactoryError> {
         let admin = Self::admin(e)?;
         admin.require_auth();
-        
-        e.storage().instance().set(&DataKey::Admin, &new_admin);
+
+        let operation = timelock::schedule(e, TimelockAction::SetAdmin(new_admin))?;
+        Ok(operation.id)
+    }
+
+    /// Schedule a change of the timelock delay (only admin)
+    pub fn set_timelock_delay(e: &Env, delay: u64) -> Result<u64, SpotFactoryError> {
+        let admin = Self::admin(e)?;
+        admin.require_auth();
+
+        let operation = timelock::schedule(e, TimelockAction::SetDelay(delay))?;
+        Ok(operation.id)
+    }
+
+    /// Execute a timelocked operation whose ETA has passed (only admin)
+    pub fn execute_operation(e: &Env, id: u64) -> Result<(), SpotFactoryError> {
+        let admin = Self::admin(e)?;
+        admin.require_auth();
+
+        match timelock::take_ready(e, id)? {
+            TimelockAction::SetAdmin(new_admin) => {
+                let pending = PendingAdmin {
+                    account: new_admin.clone(),
+                    live_until_ledger: e.ledger().sequence() + ACCEPT_WINDOW_LEDGERS,
+                };
+                e.storage().instance().set(&DataKey::PendingAdmin, &pending);
+                AdminTransferProposed {
+                    new_admin,
+                    live_until_ledger: pending.live_until_ledger,
+                }
+                .publish(e);
+            }
+            TimelockAction::SetDelay(delay) => timelock::set_delay(e, delay)?,
+            TimelockAction::SetEventWasm(wasm_hash) => Self::write_event_wasm_hash(e, wasm_hash),
+            // The factory has no admin roles and is not upgradeable
+            TimelockAction::GrantAdmin(_) | TimelockAction::Upgrade(_) => {
+                return Err(SpotFactoryError::InvalidParameters)
+            }
+        }
         Ok(())
     }
 
+    /// Accept an admin transfer offered through `set_admin` (only the proposed admin,
+    /// before the offer expires)
+    pub fn accept_admin(e: &Env) -> Result<(), SpotFactoryError> {
+        let pending = Self::pending_admin(e).ok_or(SpotFactoryError::Unauthorized)?;
+        pending.account.require_auth();
+        if e.ledger().sequence() > pending.live_until_ledger {
+            return Err(SpotFactoryError::TransferExpired);
+        }
+
+        let previous_admin = Self::admin(e)?;
+        e.storage().instance().set(&DataKey::Admin, &pending.account);
+        e.storage().instance().remove(&DataKey::PendingAdmin);
+
+        AdminChanged {
+            previous_admin,
+            new_admin: pending.account,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    /// Admin transfer awaiting acceptance, if any
+    pub fn pending_admin(e: &Env) -> Option<PendingAdmin> {
+        e.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    /// Cancel a pending timelocked operation (only admin)
+    pub fn cancel_operation(e: &Env, id: u64) -> Result<(), SpotFactoryError> {
+        let admin = Self::admin(e)?;
+        admin.require_auth();
+
+        timelock::cancel(e, id)?;
+        Ok(())
+    }
+
+    /// List pending timelocked operations
+    pub fn pending_operations(e: &Env) -> Vec<Operation> {
+        timelock::pending(e)
+    }
+
+    /// Current timelock delay in seconds
+    pub fn timelock_delay(e: &Env) -> u64 {
+        timelock::delay(e)
+    }
+
+    /// Set the WASM hash of the spot-event contract deployed by `create_event`
+    /// (only admin). The first hash applies immediately and bypasses the timelock, so
+    /// the factory can start deploying right after its own deployment; later changes
+    /// are scheduled and applied by `execute_operation` once the timelock delay has
+    /// passed.
+    ///
+    /// # Returns
+    /// The ID of the scheduled operation, or None if the hash was set immediately
+    pub fn set_event_wasm_hash(e: &Env, wasm_hash: BytesN<32>) -> Result<Option<u64>, SpotFactoryError> {
+        let admin = Self::admin(e)?;
+        admin.require_auth();
+
+        if !e.storage().instance().has(&DataKey::EventWasmHash) {
+            Self::write_event_wasm_hash(e, wasm_hash);
+            return Ok(None);
+        }
+        let operation = timelock::schedule(e, TimelockAction::SetEventWasm(wasm_hash))?;
+        Ok(Some(operation.id))
+    }
+
+    /// Get the WASM hash used to deploy spot-event contracts
+    pub fn event_wasm_hash(e: &Env) -> Result<BytesN<32>, SpotFactoryError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::EventWasmHash)
+            .ok_or(SpotFactoryError::EventWasmNotSet)
+    }
+
     /// Create a new event
     /// This will deploy a new Event contract instance
     /// 
     /// # Arguments
-    /// * `creator` - Address of the event creator
+    /// * `creator` - Address of the event creator (becomes the event contract owner)
     /// * `event_name` - Name of the event
+    /// * `event_date` - Date of the event (Unix timestamp)
+    /// * `location` - Location of the event
+    /// * `description` - Event description
     /// * `max_nfts` - Maximum number of NFTs that can be minted
+    /// * `claim_start` - Claim period start timestamp
+    /// * `claim_end` - Claim period end timestamp
     /// * `metadata_uri` - URI pointing to event metadata JSON
+    /// * `image_url` - URL of the event image
     /// 
     /// # Returns
     /// The contract address of the newly created event
@@ -69,83 +206,139 @@ impl SpotFactory {
         e: &Env,
         creator: Address,
         event_name: String,
+        event_date: u64,
+        location: String,
+        description: String,
         max_nfts: u32,
+        claim_start: u64,
+        claim_end: u64,
         metadata_uri: String,
+        image_url: String,
     ) -> Result<Address, SpotFactoryError> {
         creator.require_auth();
 
-        // Validate parameters
-        if max_nfts == 0 {
+        // Validate parameters before deploying, so the event constructor never panics
+        if max_nfts == 0 || event_name.is_empty() {
             return Err(SpotFactoryError::InvalidParameters);
         }
+        if claim_end < claim_start {
+            return Err(SpotFactoryError::InvalidClaimPeriod);
+        }
 
-        // TODO: Deploy event contract using deployer
-        // For now, this is a placeholder structure
-        // In production, this would:
-        // 1. Deploy the event contract WASM
-        // 2. Initialize it with the event parameters
-        // 3. Store the event info
-        
-        // Generate event ID (simplified - in production use proper ID generation)
-        // For now, use a combination of creator address and timestamp
-        // In production, this should be a proper hash or sequential ID
-        let timestamp = e.ledger().timestamp();
-        let creator_str = creator.to_string();
-        // Create a simple ID from creator address (first chars) + timestamp
-        // Note: This is a simplified version, production should use proper ID generation
-        let event_id_str = String::from_str(e, "EVENT");
-
-        // TODO: Actual contract deployment will happen here
-        // let event_wasm_hash = BytesN::<32>::from_array(e, &[0u8; 32]); // Placeholder
-        // let event_contract = e.deployer().deploy_contract(event_wasm_hash, creator.clone());
-        
-        // For now, return a placeholder address
-        // In production, this will be the actual deployed contract address
-        let event_address = creator.clone(); // Placeholder
+        let wasm_hash = Self::event_wasm_hash(e)?;
+        let count: u32 = e.storage().instance().get(&DataKey::EventCount).unwrap_or(0u32);
+        let event_id = count + 1;
+
+        // Each deployment needs its own salt; the sequential event ID is unique per factory
+        let mut salt = [0u8; 32];
+        salt[28..].copy_from_slice(&event_id.to_be_bytes());
+
+        let event_address = e
+            .deployer()
+            .with_current_contract(BytesN::from_array(e, &salt))
+            .deploy_v2(
+                wasm_hash,
+                (
+                    creator.clone(),
+                    event_name.clone(),
+                    event_date,
+                    location,
+                    description,
+                    max_nfts,
+                    claim_start,
+                    claim_end,
+                    metadata_uri,
+                    image_url,
+                ),
+            );
 
         // Store event info
         let event_info = EventInfo {
-            id: event_id_str.clone(),
+            id: event_id,
             contract_address: event_address.clone(),
             creator: creator.clone(),
             name: event_name.clone(),
             created_at: e.ledger().timestamp(),
         };
 
-        e.storage()
-            .instance()
-            .set(&DataKey::EventInfo(event_id_str.clone()), &event_info);
+        storage::write(e, &DataKey::EventInfo(event_id), &event_info);
+        storage::write(e, &DataKey::EventByContract(event_address.clone()), &event_id);
 
-        // Add to events list
-        let mut events: Vec<String> = e
-            .storage()
-            .instance()
-            .get(&DataKey::Events)
-            .unwrap_or_else(|| Vec::new(&e));
-        events.push_back(event_id_str);
-        e.storage().instance().set(&DataKey::Events, &events);
+        // Index the event under its creator
+        let creator_count = Self::get_creator_event_count(e, creator.clone());
+        storage::write(e, &DataKey::CreatorEvent(creator.clone(), creator_count), &event_id);
+        storage::write(e, &DataKey::CreatorEventCount(creator.clone()), &(creator_count + 1));
         
         // Update event counter
-        let count: u32 = e.storage().instance().get(&DataKey::EventCount).unwrap_or(0u32);
-        e.storage().instance().set(&DataKey::EventCount, &(count + 1));
+        e.storage().instance().set(&DataKey::EventCount, &event_id);
+        storage::extend_instance(e);
+
+        EventDeployed {
+            event_id,
+            creator,
+            contract_address: event_address.clone(),
+            name: event_name,
+        }
+        .publish(e);
 
         Ok(event_address)
     }
 
     /// Get event information by ID
-    pub fn get_event_info(e: &Env, event_id: String) -> Result<EventInfo, SpotFactoryError> {
-        e.storage()
-            .instance()
-            .get(&DataKey::EventInfo(event_id))
+    pub fn get_event_info(e: &Env, event_id: u32) -> Result<EventInfo, SpotFactoryError> {
+        storage::read(e, &DataKey::EventInfo(event_id)).ok_or(SpotFactoryError::EventNotFound)
+    }
+
+    /// Get the event ID of a deployed event contract
+    pub fn get_event_id_by_contract(
+        e: &Env,
+        contract_address: Address,
+    ) -> Result<u32, SpotFactoryError> {
+        storage::read(e, &DataKey::EventByContract(contract_address))
             .ok_or(SpotFactoryError::EventNotFound)
     }
 
-    /// Get all event IDs
-    pub fn get_events(e: &Env) -> Vec<String> {
-        e.storage()
-            .instance()
-            .get(&DataKey::Events)
-            .unwrap_or_else(|| Vec::new(&e))
+    /// List events in creation order
+    /// 
+    /// # Arguments
+    /// * `start` - Index of the first event to return (0-based)
+    /// * `limit` - Maximum number of events to return (capped at 50)
+    pub fn get_events(e: &Env, start: u32, limit: u32) -> Vec<EventInfo> {
+        let count = Self::get_event_count(e);
+        let end = count.min(start.saturating_add(limit.min(MAX_PAGE_SIZE)));
+
+        let mut events: Vec<EventInfo> = Vec::new(e);
+        for index in start..end {
+            if let Some(info) = storage::read(e, &DataKey::EventInfo(index + 1)) {
+                events.push_back(info);
+            }
+        }
+        events
+    }
+
+    /// List the events created by a specific creator
+    /// 
+    /// # Arguments
+    /// * `creator` - Address of the event creator
+    /// * `start` - Index of the first event to return (0-based, per creator)
+    /// * `limit` - Maximum number of events to return (capped at 50)
+    pub fn get_events_by_creator(e: &Env, creator: Address, start: u32, limit: u32) -> Vec<EventInfo> {
+        let count = Self::get_creator_event_count(e, creator.clone());
+        let end = count.min(start.saturating_add(limit.min(MAX_PAGE_SIZE)));
+
+        let mut events: Vec<EventInfo> = Vec::new(e);
+        for index in start..end {
+            let event_id: Option<u32> = storage::read(e, &DataKey::CreatorEvent(creator.clone(), index));
+            if let Some(info) = event_id.and_then(|id| storage::read(e, &DataKey::EventInfo(id))) {
+                events.push_back(info);
+            }
+        }
+        events
+    }
+
+    /// Get the number of events created by a specific creator
+    pub fn get_creator_event_count(e: &Env, creator: Address) -> u32 {
+        storage::read(e, &DataKey::CreatorEventCount(creator)).unwrap_or(0u32)
     }
 
     /// Get total number of events created
@@ -155,5 +348,10 @@ impl SpotFactory {
             .get(&DataKey::EventCount)
             .unwrap_or(0u32)
     }
+
+    fn write_event_wasm_hash(e: &Env, wasm_hash: BytesN<32>) {
+        e.storage().instance().set(&DataKey::EventWasmHash, &wasm_hash);
+        EventWasmUpdated { wasm_hash }.publish(e);
+    }
 }
 
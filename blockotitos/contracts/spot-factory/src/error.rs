@@ -1,4 +1,5 @@
 use soroban_sdk::{contracterror, symbol_short, Symbol};
+use spot_timelock::TimelockError;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -16,6 +17,16 @@ pub enum SpotFactoryError {
     InvalidParameters = 5,
     /// Event not found: Event ID does not exist
     EventNotFound = 6,
+    /// Invalid claim period: claim_end is before claim_start
+    InvalidClaimPeriod = 7,
+    /// Event WASM not set: Admin has not configured the spot-event WASM hash
+    EventWasmNotSet = 8,
+    /// Operation not found: Timelocked operation was never scheduled, or already executed or cancelled
+    OperationNotFound = 9,
+    /// Operation not ready: Timelocked operation's ETA has not passed yet
+    OperationNotReady = 10,
+    /// Transfer expired: Pending admin transfer was not accepted in time
+    TransferExpired = 11,
 }
 
 impl SpotFactoryError {
@@ -27,7 +38,21 @@ impl SpotFactoryError {
             SpotFactoryError::EventCreationFailed => symbol_short!("EVT_FAIL"),
             SpotFactoryError::InvalidParameters => symbol_short!("INV_PARAM"),
             SpotFactoryError::EventNotFound => symbol_short!("NO_EVENT"),
+            SpotFactoryError::InvalidClaimPeriod => symbol_short!("INV_CLAIM"),
+            SpotFactoryError::EventWasmNotSet => symbol_short!("NO_WASM"),
+            SpotFactoryError::OperationNotFound => symbol_short!("NO_OP"),
+            SpotFactoryError::OperationNotReady => symbol_short!("OP_LOCKED"),
+            SpotFactoryError::TransferExpired => symbol_short!("XFER_EXP"),
         }
     }
 }
 
+impl From<TimelockError> for SpotFactoryError {
+    fn from(error: TimelockError) -> Self {
+        match error {
+            TimelockError::NotFound => SpotFactoryError::OperationNotFound,
+            TimelockError::NotReady => SpotFactoryError::OperationNotReady,
+            TimelockError::Rejected => SpotFactoryError::InvalidParameters,
+        }
+    }
+}
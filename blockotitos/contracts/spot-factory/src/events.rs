@@ -0,0 +1,42 @@
+//! SPOT Factory contract events
+//!
+//! Published on every state change of the factory. Topics are fixed: the event name
+//! first, then the indexed fields in declaration order.
+
+use soroban_sdk::{contractevent, Address, BytesN, String};
+
+/// Published when a new event contract is deployed
+#[contractevent(topics = ["event_deployed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventDeployed {
+    #[topic]
+    pub event_id: u32,
+    #[topic]
+    pub creator: Address,
+    pub contract_address: Address,
+    pub name: String,
+}
+
+/// Published when the factory admin changes
+#[contractevent(topics = ["admin_changed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminChanged {
+    pub previous_admin: Address,
+    pub new_admin: Address,
+}
+
+/// Published when an admin transfer is offered to `new_admin`, who must accept it by
+/// `live_until_ledger`
+#[contractevent(topics = ["admin_transfer_proposed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferProposed {
+    pub new_admin: Address,
+    pub live_until_ledger: u32,
+}
+
+/// Published when the spot-event WASM hash is updated
+#[contractevent(topics = ["event_wasm_updated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventWasmUpdated {
+    pub wasm_hash: BytesN<32>,
+}
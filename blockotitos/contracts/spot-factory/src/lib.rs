@@ -3,6 +3,8 @@ use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env, St
 
 mod contract;
 mod error;
+mod events;
+mod storage;
 
 pub use contract::SpotFactory;
 use error::SpotFactoryError;
@@ -1,16 +1,34 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{symbol_short, testutils::Address as _, Address, BytesN, Env, String};
+use crate::contract::{DataKey, SpotFactoryClient};
+use crate::storage::PERSISTENT_BUMP_AMOUNT;
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address, BytesN, Env, String};
+
+// Requires the spot-event WASM: run
+// `cargo build --target wasm32v1-none --release --package spot-event` before `cargo test`
+mod spot_event {
+    soroban_sdk::contractimport!(file = "../../target/wasm32v1-none/release/spot_event.wasm");
+}
+
+fn create_client<'a>(e: &'a Env, admin: &Address) -> SpotFactoryClient<'a> {
+    e.mock_all_auths();
+    let address = e.register(SpotFactory, (admin,));
+    SpotFactoryClient::new(e, &address)
+}
+
+fn upload_event_wasm(e: &Env, client: &SpotFactoryClient) {
+    let wasm_hash = e.deployer().upload_contract_wasm(spot_event::WASM);
+    client.set_event_wasm_hash(&wasm_hash);
+}
 
 #[test]
 fn test_constructor() {
     let e = Env::default();
     let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
 
-    SpotFactory::__constructor(&e, admin.clone());
-    
-    let stored_admin = SpotFactory::admin(&e).unwrap();
+    let stored_admin = client.admin();
     assert_eq!(stored_admin, admin);
 }
 
@@ -19,14 +37,86 @@ fn test_set_admin() {
     let e = Env::default();
     let admin = Address::generate(&e);
     let new_admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    // Schedule new admin; it only takes effect after the timelock delay
+    let id = client.set_admin(&new_admin);
+    assert_eq!(client.pending_operations().len(), 1);
+    let result = client.try_execute_operation(&id);
+    assert_eq!(result.unwrap_err(), Ok(SpotFactoryError::OperationNotReady));
+    assert_eq!(client.admin(), admin);
+
+    e.ledger().with_mut(|li| li.timestamp += client.timelock_delay());
+    client.execute_operation(&id);
+    assert_eq!(client.pending_operations().len(), 0);
 
-    SpotFactory::__constructor(&e, admin.clone());
-    
-    // Set new admin
-    SpotFactory::set_admin(&e, new_admin.clone());
-    
-    let stored_admin = SpotFactory::admin(&e).unwrap();
+    // The new admin must accept before the offer expires
+    assert_eq!(client.admin(), admin);
+    assert_eq!(client.pending_admin().unwrap().account, new_admin);
+    client.accept_admin();
+
+    let stored_admin = client.admin();
     assert_eq!(stored_admin, new_admin);
+    assert!(client.pending_admin().is_none());
+}
+
+#[test]
+fn test_admin_transfer_expires() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let id = client.set_admin(&Address::generate(&e));
+    e.ledger().with_mut(|li| li.timestamp += client.timelock_delay());
+    client.execute_operation(&id);
+
+    let live_until = client.pending_admin().unwrap().live_until_ledger;
+    e.ledger().with_mut(|li| li.sequence_number = live_until + 1);
+    let result = client.try_accept_admin();
+    assert_eq!(result.unwrap_err(), Ok(SpotFactoryError::TransferExpired));
+    assert_eq!(client.admin(), admin);
+}
+
+#[test]
+fn test_cancel_admin_change() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let id = client.set_admin(&Address::generate(&e));
+    client.cancel_operation(&id);
+    e.ledger().with_mut(|li| li.timestamp += client.timelock_delay());
+
+    let result = client.try_execute_operation(&id);
+    assert_eq!(result.unwrap_err(), Ok(SpotFactoryError::OperationNotFound));
+    assert_eq!(client.admin(), admin);
+}
+
+#[test]
+fn test_event_wasm_hash_change_is_timelocked() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    // The first hash applies immediately
+    let initial = BytesN::from_array(&e, &[1u8; 32]);
+    assert_eq!(client.set_event_wasm_hash(&initial), None);
+    assert_eq!(client.event_wasm_hash(), initial);
+
+    // Replacing it waits for the timelock delay
+    let replacement = BytesN::from_array(&e, &[2u8; 32]);
+    let id = client.set_event_wasm_hash(&replacement).unwrap();
+    assert_eq!(
+        client.pending_operations().get(0).unwrap().action,
+        spot_timelock::TimelockAction::SetEventWasm(replacement.clone())
+    );
+    let result = client.try_execute_operation(&id);
+    assert_eq!(result.unwrap_err(), Ok(SpotFactoryError::OperationNotReady));
+    assert_eq!(client.event_wasm_hash(), initial);
+
+    e.ledger().with_mut(|li| li.timestamp += client.timelock_delay());
+    client.execute_operation(&id);
+    assert_eq!(client.event_wasm_hash(), replacement);
 }
 
 #[test]
@@ -34,23 +124,134 @@ fn test_create_event() {
     let e = Env::default();
     let admin = Address::generate(&e);
     let creator = Address::generate(&e);
-
-    SpotFactory::__constructor(&e, admin.clone());
+    let client = create_client(&e, &admin);
+    upload_event_wasm(&e, &client);
 
     let event_name = String::from_str(&e, "Hackathon Stellar 2024");
     let max_nfts = 100u32;
     let metadata_uri = String::from_str(&e, "https://example.com/metadata.json");
 
-    // TODO: Implement actual contract deployment for full test
-    // For now, this tests the basic structure
-    let result = SpotFactory::create_event(
-        &e,
-        creator.clone(),
-        event_name.clone(),
-        max_nfts,
-        metadata_uri.clone(),
+    let event_address = client.create_event(
+        &creator,
+        &event_name,
+        &1735689600u64,
+        &String::from_str(&e, "Bogotá, Colombia"),
+        &String::from_str(&e, "Annual Stellar Hackathon"),
+        &max_nfts,
+        &0u64,
+        &u64::MAX,
+        &metadata_uri,
+        &String::from_str(&e, "https://example.com/image.png"),
     );
 
-    assert!(result.is_ok());
+    let event_client = spot_event::Client::new(&e, &event_address);
+    assert_eq!(event_client.owner(), creator);
+
+    let (name, _, _, _, max, minted, _, _, uri, _) = event_client.get_event_info();
+    assert_eq!(name, event_name);
+    assert_eq!(max, max_nfts);
+    assert_eq!(minted, 0);
+    assert_eq!(uri, metadata_uri);
+
+    assert_eq!(client.get_event_count(), 1);
 }
 
+#[test]
+fn test_create_event_validates_parameters() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let creator = Address::generate(&e);
+    let client = create_client(&e, &admin);
+
+    let name = String::from_str(&e, "Test Event");
+    let text = String::from_str(&e, "Test");
+
+    // No WASM hash configured yet
+    let result = client.try_create_event(
+        &creator, &name, &0u64, &text, &text, &10u32, &0u64, &100u64, &text, &text,
+    );
+    assert_eq!(result.unwrap_err(), Ok(SpotFactoryError::EventWasmNotSet));
+
+    upload_event_wasm(&e, &client);
+
+    let result = client.try_create_event(
+        &creator, &name, &0u64, &text, &text, &0u32, &0u64, &100u64, &text, &text,
+    );
+    assert_eq!(result.unwrap_err(), Ok(SpotFactoryError::InvalidParameters));
+
+    let result = client.try_create_event(
+        &creator, &name, &0u64, &text, &text, &10u32, &100u64, &50u64, &text, &text,
+    );
+    assert_eq!(result.unwrap_err(), Ok(SpotFactoryError::InvalidClaimPeriod));
+
+    assert_eq!(client.get_event_count(), 0);
+}
+
+fn create_test_event(e: &Env, client: &SpotFactoryClient, creator: &Address, name: &str) -> Address {
+    let text = String::from_str(e, "Test");
+    client.create_event(
+        creator,
+        &String::from_str(e, name),
+        &1735689600u64,
+        &text,
+        &text,
+        &10u32,
+        &0u64,
+        &u64::MAX,
+        &text,
+        &text,
+    )
+}
+
+#[test]
+fn test_event_registry() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let creator1 = Address::generate(&e);
+    let creator2 = Address::generate(&e);
+    let client = create_client(&e, &admin);
+    upload_event_wasm(&e, &client);
+
+    let address1 = create_test_event(&e, &client, &creator1, "Event 1");
+    let address2 = create_test_event(&e, &client, &creator2, "Event 2");
+    let address3 = create_test_event(&e, &client, &creator1, "Event 3");
+
+    // Each event gets its own ID and contract
+    assert_ne!(address1, address2);
+    assert_eq!(client.get_event_count(), 3);
+    assert_eq!(client.get_event_id_by_contract(&address1), 1);
+    assert_eq!(client.get_event_id_by_contract(&address2), 2);
+    assert_eq!(client.get_event_id_by_contract(&address3), 3);
+
+    let info = client.get_event_info(&2);
+    assert_eq!(info.contract_address, address2);
+    assert_eq!(info.creator, creator2);
+    assert_eq!(info.name, String::from_str(&e, "Event 2"));
+
+    // Paginated listing
+    let page = client.get_events(&0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().id, 1);
+    assert_eq!(page.get(1).unwrap().id, 2);
+    let page = client.get_events(&2, &2);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, 3);
+
+    // Lookup by creator
+    assert_eq!(client.get_creator_event_count(&creator1), 2);
+    let creator_events = client.get_events_by_creator(&creator1, &0, &10);
+    assert_eq!(creator_events.len(), 2);
+    assert_eq!(creator_events.get(0).unwrap().contract_address, address1);
+    assert_eq!(creator_events.get(1).unwrap().contract_address, address3);
+
+    let result = client.try_get_event_id_by_contract(&Address::generate(&e));
+    assert_eq!(result.unwrap_err(), Ok(SpotFactoryError::EventNotFound));
+
+    // Reading the registry extends the TTL of its entries
+    e.ledger().with_mut(|li| li.sequence_number += 2 * 17280);
+    client.get_event_info(&1);
+    let ttl = e.as_contract(&client.address, || {
+        e.storage().persistent().get_ttl(&DataKey::EventInfo(1))
+    });
+    assert_eq!(ttl, PERSISTENT_BUMP_AMOUNT);
+}
@@ -0,0 +1,207 @@
+//! Timelock for sensitive admin operations
+//!
+//! Shared by the SPOT contracts. A sensitive operation (admin change, admin grant,
+//! upgrade, delay change) is first scheduled with an ETA of `now + delay`, can be
+//! cancelled while pending, and can only be executed once the ETA has passed. This
+//! gives the team a window to react if an admin key leaks.
+//!
+//! The module only keeps the queue; each contract checks who may schedule, execute or
+//! cancel, and performs the action returned by [`take_ready`] itself. All state lives in
+//! the calling contract's instance storage.
+
+#![no_std]
+
+use soroban_sdk::{contractevent, contracttype, Address, BytesN, Env, Vec};
+
+/// Delay applied until a `SetDelay` operation is executed (two days)
+pub const DEFAULT_DELAY: u64 = 2 * 24 * 60 * 60;
+
+/// Maximum delay that can be configured (30 days)
+pub const MAX_DELAY: u64 = 30 * 24 * 60 * 60;
+
+/// Maximum number of operations pending at the same time
+pub const MAX_PENDING: u32 = 20;
+
+/// Ledgers (~7 days) the new admin has to accept a transfer once its `SetAdmin`
+/// operation is executed
+pub const ACCEPT_WINDOW_LEDGERS: u32 = 7 * 17280;
+
+/// Storage keys, prefixed so they never collide with the contract's own keys
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TimelockKey {
+    TimelockDelay,
+    TimelockNextId,
+    TimelockPending,           // Vec<u64> of pending operation IDs
+    TimelockOperation(u64),
+}
+
+/// Sensitive actions that go through the timelock
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TimelockAction {
+    SetAdmin(Address),
+    GrantAdmin(Address),
+    Upgrade(BytesN<32>),
+    SetDelay(u64),
+    /// Replace the WASM hash a factory deploys child contracts from
+    SetEventWasm(BytesN<32>),
+}
+
+/// A scheduled operation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Operation {
+    pub id: u64,
+    pub action: TimelockAction,
+    pub eta: u64,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TimelockError {
+    /// The operation does not exist (never scheduled, executed or cancelled)
+    NotFound,
+    /// The operation's ETA has not passed yet
+    NotReady,
+    /// Too many pending operations, or an invalid delay
+    Rejected,
+}
+
+/// Published when an operation is scheduled
+#[contractevent(topics = ["operation_scheduled"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperationScheduled {
+    #[topic]
+    pub id: u64,
+    pub action: TimelockAction,
+    pub eta: u64,
+}
+
+/// Published when an operation is executed
+#[contractevent(topics = ["operation_executed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperationExecuted {
+    #[topic]
+    pub id: u64,
+}
+
+/// Published when a pending operation is cancelled
+#[contractevent(topics = ["operation_cancelled"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperationCancelled {
+    #[topic]
+    pub id: u64,
+}
+
+/// Current delay in seconds
+pub fn delay(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&TimelockKey::TimelockDelay)
+        .unwrap_or(DEFAULT_DELAY)
+}
+
+/// Set the delay directly. Only call from the constructor or when executing a
+/// `SetDelay` operation.
+pub fn set_delay(e: &Env, delay: u64) -> Result<(), TimelockError> {
+    if delay > MAX_DELAY {
+        return Err(TimelockError::Rejected);
+    }
+    e.storage().instance().set(&TimelockKey::TimelockDelay, &delay);
+    Ok(())
+}
+
+/// Queue an action with an ETA of `now + delay`
+pub fn schedule(e: &Env, action: TimelockAction) -> Result<Operation, TimelockError> {
+    if let TimelockAction::SetDelay(delay) = action {
+        if delay > MAX_DELAY {
+            return Err(TimelockError::Rejected);
+        }
+    }
+
+    let mut pending = pending_ids(e);
+    if pending.len() >= MAX_PENDING {
+        return Err(TimelockError::Rejected);
+    }
+
+    let instance = e.storage().instance();
+    let id: u64 = instance.get(&TimelockKey::TimelockNextId).unwrap_or(1u64);
+    let operation = Operation {
+        id,
+        action,
+        eta: e.ledger().timestamp() + delay(e),
+    };
+
+    instance.set(&TimelockKey::TimelockOperation(id), &operation);
+    instance.set(&TimelockKey::TimelockNextId, &(id + 1));
+    pending.push_back(id);
+    instance.set(&TimelockKey::TimelockPending, &pending);
+
+    OperationScheduled {
+        id,
+        action: operation.action.clone(),
+        eta: operation.eta,
+    }
+    .publish(e);
+
+    Ok(operation)
+}
+
+/// Remove a ready operation from the queue and return its action for the caller to
+/// perform
+pub fn take_ready(e: &Env, id: u64) -> Result<TimelockAction, TimelockError> {
+    let operation = get(e, id).ok_or(TimelockError::NotFound)?;
+    if e.ledger().timestamp() < operation.eta {
+        return Err(TimelockError::NotReady);
+    }
+
+    remove(e, id);
+    OperationExecuted { id }.publish(e);
+    Ok(operation.action)
+}
+
+/// Cancel a pending operation
+pub fn cancel(e: &Env, id: u64) -> Result<(), TimelockError> {
+    get(e, id).ok_or(TimelockError::NotFound)?;
+
+    remove(e, id);
+    OperationCancelled { id }.publish(e);
+    Ok(())
+}
+
+/// Get a pending operation
+pub fn get(e: &Env, id: u64) -> Option<Operation> {
+    e.storage().instance().get(&TimelockKey::TimelockOperation(id))
+}
+
+/// All pending operations, oldest first
+pub fn pending(e: &Env) -> Vec<Operation> {
+    let mut operations = Vec::new(e);
+    for id in pending_ids(e).iter() {
+        if let Some(operation) = get(e, id) {
+            operations.push_back(operation);
+        }
+    }
+    operations
+}
+
+fn pending_ids(e: &Env) -> Vec<u64> {
+    e.storage()
+        .instance()
+        .get(&TimelockKey::TimelockPending)
+        .unwrap_or(Vec::new(e))
+}
+
+fn remove(e: &Env, id: u64) {
+    let instance = e.storage().instance();
+    instance.remove(&TimelockKey::TimelockOperation(id));
+
+    let mut pending = pending_ids(e);
+    if let Some(index) = pending.first_index_of(id) {
+        pending.remove(index);
+    }
+    instance.set(&TimelockKey::TimelockPending, &pending);
+}
+
+#[cfg(test)]
+mod test;
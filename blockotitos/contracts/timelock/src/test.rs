@@ -0,0 +1,63 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{contract, contractimpl, testutils::{Address as _, Ledger}, Address, Env};
+
+#[contract]
+struct TimelockTest;
+
+#[contractimpl]
+impl TimelockTest {}
+
+#[test]
+fn test_schedule_and_execute() {
+    let e = Env::default();
+    let contract = e.register(TimelockTest, ());
+    let new_admin = Address::generate(&e);
+
+    e.as_contract(&contract, || {
+        assert_eq!(delay(&e), DEFAULT_DELAY);
+        let operation = schedule(&e, TimelockAction::SetAdmin(new_admin.clone())).unwrap();
+        assert_eq!(operation.id, 1);
+        assert_eq!(operation.eta, DEFAULT_DELAY);
+        assert_eq!(pending(&e).len(), 1);
+
+        assert_eq!(take_ready(&e, 1), Err(TimelockError::NotReady));
+    });
+
+    e.ledger().with_mut(|li| li.timestamp = DEFAULT_DELAY);
+    e.as_contract(&contract, || {
+        assert_eq!(take_ready(&e, 1), Ok(TimelockAction::SetAdmin(new_admin.clone())));
+        assert_eq!(take_ready(&e, 1), Err(TimelockError::NotFound));
+        assert_eq!(pending(&e).len(), 0);
+    });
+}
+
+#[test]
+fn test_cancel_and_limits() {
+    let e = Env::default();
+    let contract = e.register(TimelockTest, ());
+
+    e.as_contract(&contract, || {
+        set_delay(&e, 60).unwrap();
+        let first = schedule(&e, TimelockAction::SetDelay(120)).unwrap();
+        let second = schedule(&e, TimelockAction::SetDelay(180)).unwrap();
+
+        cancel(&e, first.id).unwrap();
+        assert_eq!(cancel(&e, first.id), Err(TimelockError::NotFound));
+        assert_eq!(pending(&e), Vec::from_array(&e, [second]));
+
+        assert_eq!(set_delay(&e, MAX_DELAY + 1), Err(TimelockError::Rejected));
+        assert_eq!(
+            schedule(&e, TimelockAction::SetDelay(MAX_DELAY + 1)).unwrap_err(),
+            TimelockError::Rejected
+        );
+        for _ in 1..MAX_PENDING {
+            schedule(&e, TimelockAction::SetDelay(60)).unwrap();
+        }
+        assert_eq!(
+            schedule(&e, TimelockAction::SetDelay(60)).unwrap_err(),
+            TimelockError::Rejected
+        );
+    });
+}